@@ -2,24 +2,33 @@ use std::str::FromStr;
 
 use cosmwasm_std::{ensure, ensure_eq, entry_point};
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Env, Event, MessageInfo, Response, StdResult, SubMsg,
-    Uint128, WasmMsg,
+    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg, Uint128,
+    WasmMsg,
 };
 use cw2::set_contract_version;
 use cw20::{AllowanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
 
 use cw_common::asset_manager_msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use cw_common::helpers::{get_fee, get_protocols, verify_protocol};
+use cw_common::decimals::{from_hub_amount, to_hub_amount, HUB_DECIMALS};
+use cw_common::events::{
+    asset_decimals_configured_event, deposit_event, deposit_revert_event,
+    native_token_configured_event, rate_limit_updated_event, recipient_format_configured_event,
+    withdraw_event, xcall_configured_event,
+};
+use cw_common::messages::HubMessage;
 use cw_common::network_address::IconAddressValidation;
-use cw_common::network_address::NetworkAddress;
+use cw_common::network_address::{
+    recipient_format, validate_recipient, AddressFormat, NetworkAddress,
+};
 use cw_common::rate_limit::RateLimit;
 use cw_common::x_call_msg::XCallMsg;
+use cw_common::xcall_client::{XCallClient, XCallManagerClient};
 use cw_common::xcall_data_types::Deposit;
 
 use crate::constants::SUCCESS_REPLY_MSG;
 use crate::contract::exec::setup;
 use crate::error::ContractError;
-use crate::helpers::{decode_encoded_bytes, is_contract, DecodedStruct};
+use crate::helpers::is_contract;
 use crate::state::*;
 
 // version info for migration info
@@ -67,11 +76,11 @@ pub fn execute(
             data,
             protocols,
         } => {
-            let xcall_manger = X_CALL_MANAGER.load(deps.storage)?;
-            let res = verify_protocol(&deps, xcall_manger, protocols);
-            if res.is_err() {
-                return Err(ContractError::Unauthorized);
-            }
+            let xcall_manager = XCallManagerClient::new(X_CALL_MANAGER.load(deps.storage)?);
+            ensure!(
+                xcall_manager.verify_protocols(&deps.querier, protocols)?,
+                ContractError::Unauthorized
+            );
 
             exec::handle_xcall_msg(deps, env, info, from, data)
         }
@@ -98,10 +107,33 @@ pub fn execute(
             };
 
             RATE_LIMITS
-                .save(deps.storage, asset, &limit)
+                .save(deps.storage, asset.clone(), &limit)
                 .map_err(ContractError::Std)?;
 
-            Ok(Response::default())
+            Ok(Response::new().add_event(rate_limit_updated_event(&asset, &limit)))
+        }
+        ExecuteMsg::ConfigureAssetDecimals { asset, decimals } => {
+            let owner = OWNER.load(deps.storage).map_err(ContractError::Std)?;
+            ensure_eq!(owner, info.sender, ContractError::OnlyOwner);
+            // reject decimals the hub amount could never be scaled by
+            to_hub_amount(1, decimals)?;
+
+            ASSET_DECIMALS
+                .save(deps.storage, asset.clone(), &decimals)
+                .map_err(ContractError::Std)?;
+
+            Ok(Response::new().add_event(asset_decimals_configured_event(&asset, decimals)))
+        }
+        ExecuteMsg::ConfigureRecipientFormat { nid, format } => {
+            let owner = OWNER.load(deps.storage).map_err(ContractError::Std)?;
+            ensure_eq!(owner, info.sender, ContractError::OnlyOwner);
+
+            match &format {
+                Some(format) => RECIPIENT_FORMATS.save(deps.storage, nid.clone(), format)?,
+                None => RECIPIENT_FORMATS.remove(deps.storage, nid.clone()),
+            }
+
+            Ok(Response::new().add_event(recipient_format_configured_event(&nid, format.as_ref())))
         }
         ExecuteMsg::DepositDenom { denom, to, data } => {
             ensure!(
@@ -134,11 +166,7 @@ pub fn execute(
                 ContractError::InvalidFunds
             );
 
-            let recipient: NetworkAddress = match to {
-                Some(to_address) => NetworkAddress::from_str(&to_address).unwrap(),
-                // if `to` is not provided, sender address is used as recipient
-                None => depositor,
-            };
+            let recipient = exec::recipient(deps.as_ref(), to, depositor)?;
             let data = data.unwrap_or_default();
             exec::deposit_tokens(
                 deps,
@@ -168,11 +196,7 @@ pub fn execute(
             );
             ensure!(!amount.is_zero(), ContractError::InvalidAmount);
 
-            let recipient: NetworkAddress = match to {
-                Some(to_address) => NetworkAddress::from_str(&to_address).unwrap(),
-                // if `to` is not provided, sender address is used as recipient
-                None => depositor,
-            };
+            let recipient = exec::recipient(deps.as_ref(), to, depositor)?;
 
             let data = data.unwrap_or_default();
 
@@ -193,15 +217,31 @@ pub fn execute(
 mod exec {
     use std::{str::FromStr, u128};
 
-    use cosmwasm_std::{BankMsg, Coin, CosmosMsg};
-    use cw_ibc_rlp_lib::rlp::Encodable;
+    use cosmwasm_std::{BankMsg, Coin, CosmosMsg, Storage};
+    use cw_ibc_rlp_lib::rlp::{decode, Encodable};
 
     use super::*;
-    use cw_common::{
-        helpers::query_network_address, rate_limit::RateLimited, xcall_data_types::DepositRevert,
-    };
+    use cw_common::{rate_limit::RateLimited, xcall_data_types::DepositRevert};
     use cw_xcall_lib::network_address::NetId;
 
+    // `to` defaults to the depositor, explicit recipients are checked against their chain's format
+    pub fn recipient(
+        deps: Deps,
+        to: Option<String>,
+        depositor: NetworkAddress,
+    ) -> Result<NetworkAddress, ContractError> {
+        let to = match to {
+            Some(to) => NetworkAddress::from_str(&to).map_err(ContractError::Std)?,
+            None => return Ok(depositor),
+        };
+        let format = RECIPIENT_FORMATS.may_load(deps.storage, to.nid().to_string())?;
+        ensure!(
+            validate_recipient(&to, format),
+            ContractError::InvalidRecipientAddress
+        );
+        Ok(to)
+    }
+
     pub fn setup(
         deps: DepsMut,
         source_xcall: String,
@@ -214,8 +254,8 @@ mod exec {
             .addr_validate(&source_xcall)
             .map_err(ContractError::Std)?;
 
-        let xcall_network_address: NetworkAddress =
-            query_network_address(&deps, &x_call_addr).unwrap();
+        let xcall_network_address =
+            XCallClient::new(x_call_addr.clone()).network_address(&deps.querier)?;
 
         if xcall_network_address.to_string().is_empty() {
             return Err(ContractError::XAddressNotFound);
@@ -241,7 +281,11 @@ mod exec {
         ICON_NET_ID.save(deps.storage, &icon_asset_manager.nid())?;
         X_CALL_MANAGER.save(deps.storage, &xcall_manager)?;
 
-        Ok(Response::default())
+        Ok(Response::new().add_event(xcall_configured_event(
+            &x_call_addr,
+            &destination_asset_manager,
+            &xcall_manager,
+        )))
     }
 
     pub fn setup_native_token(
@@ -260,7 +304,10 @@ mod exec {
         NATIVE_TOKEN_ADDRESS.save(deps.storage, &token_addr)?;
         NATIVE_TOKEN_MANAGER.save(deps.storage, &token_manager_addr)?;
 
-        Ok(Response::default())
+        Ok(Response::new().add_event(native_token_configured_event(
+            &token_addr,
+            &token_manager_addr,
+        )))
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -330,20 +377,21 @@ mod exec {
         funds: Vec<Coin>,
     ) -> Result<Response, ContractError> {
         let dest_am = ICON_ASSET_MANAGER.load(deps.storage)?;
+        let decimals = query::query_asset_decimals(deps.as_ref(), token_address.clone())?;
 
         //create xcall rlp encode data
         let xcall_data = Deposit {
             token_address: token_address.to_owned(),
             from: from.to_string(),
             to: to.to_string(),
-            amount: Uint128::u128(&amount),
+            amount: to_hub_amount(amount.u128(), decimals)?,
             data,
         };
 
         let source_xcall = SOURCE_XCALL.load(deps.storage)?;
         //create xcall msg for dispatching  send call
-        let protocol_config =
-            get_protocols(&deps.as_ref(), X_CALL_MANAGER.load(deps.storage)?).unwrap();
+        let protocol_config = XCallManagerClient::new(X_CALL_MANAGER.load(deps.storage)?)
+            .protocols(&deps.querier, dest_am.nid())?;
         let xcall_message = XCallMsg::SendCallMessage {
             to: dest_am.to_string().parse()?,
             data: xcall_data.rlp_bytes().to_vec(),
@@ -373,13 +421,7 @@ mod exec {
             reply_on: cosmwasm_std::ReplyOn::Never,
         };
 
-        let attributes = vec![
-            ("Token", token_address),
-            ("To", to.to_string()),
-            ("Amount", amount.to_string()),
-        ];
-
-        let event = Event::new("Deposit").add_attributes(attributes);
+        let event = deposit_event(&token_address, &from, &to.to_string(), amount);
 
         let resp = Response::new()
             .add_submessages(msgs)
@@ -402,10 +444,8 @@ mod exec {
             return Err(ContractError::OnlyXcallService);
         }
 
-        let (_, decoded_struct) = decode_encoded_bytes(&data)?;
-
-        let res = match decoded_struct {
-            DecodedStruct::DepositRevert(data) => {
+        let res = match decode::<HubMessage>(&data)? {
+            HubMessage::DepositRevert(data) => {
                 if from != x_network.to_string() {
                     return Err(ContractError::FailedXcallNetworkMatch);
                 }
@@ -413,11 +453,12 @@ mod exec {
                 let token_address = data.token_address;
                 let account = data.account;
                 let amount = Uint128::from(data.amount);
+                let event = deposit_revert_event(&token_address, &account, amount);
 
-                transfer_tokens(deps, env, account, token_address, amount)?
+                transfer_tokens(deps, env, account, token_address, amount)?.add_event(event)
             }
 
-            DecodedStruct::WithdrawTo(data_struct) => {
+            HubMessage::WithdrawTo(data_struct) => {
                 let icon_am = ICON_ASSET_MANAGER.load(deps.storage)?;
                 if from != icon_am.to_string() {
                     return Err(ContractError::OnlyIconAssetManager {});
@@ -425,12 +466,15 @@ mod exec {
 
                 let token_address = data_struct.token_address;
                 let account = data_struct.user_address;
-                let amount = Uint128::from(data_struct.amount);
+                let (amount, dust) =
+                    withdraw_amount(deps.as_ref(), &token_address, data_struct.amount)?;
+                track_withdraw_dust(deps.storage, &token_address, dust)?;
+                let event = withdraw_event(&token_address, &account, amount, dust, false);
 
-                transfer_tokens(deps, env, account, token_address, amount)?
+                transfer_tokens(deps, env, account, token_address, amount)?.add_event(event)
             }
 
-            DecodedStruct::WithdrawNativeTo(data_struct) => {
+            HubMessage::WithdrawNativeTo(data_struct) => {
                 let icon_am = ICON_ASSET_MANAGER.load(deps.storage)?;
                 if from != icon_am.to_string() {
                     return Err(ContractError::OnlyIconAssetManager {});
@@ -438,15 +482,50 @@ mod exec {
 
                 let token_address = data_struct.token_address;
                 let account = data_struct.user_address;
-                let amount = Uint128::from(data_struct.amount);
+                let (amount, dust) =
+                    withdraw_amount(deps.as_ref(), &token_address, data_struct.amount)?;
+                track_withdraw_dust(deps.storage, &token_address, dust)?;
+                let event = withdraw_event(&token_address, &account, amount, dust, true);
 
-                swap_to_native(deps, env, account, token_address, amount)?
+                swap_to_native(deps, env, account, token_address, amount)?.add_event(event)
             }
+
+            _ => return Err(ContractError::UnknownMethod),
         };
 
         Ok(res)
     }
 
+    // hub amounts are rounded down to the asset's decimals, returns the local amount and the dust
+    // in hub units that is not paid out
+    fn withdraw_amount(
+        deps: Deps,
+        asset: &str,
+        amount: u128,
+    ) -> Result<(Uint128, Uint128), ContractError> {
+        let decimals = query::query_asset_decimals(deps, asset.to_string())?;
+        let local = from_hub_amount(amount, decimals)?;
+        ensure!(local != 0, ContractError::InvalidAmount);
+        let dust = Uint128::new(amount) - Uint128::new(to_hub_amount(local, decimals)?);
+        Ok((Uint128::new(local), dust))
+    }
+
+    // the hub has already debited the full amount, keeping the total lets hub and spoke supply be
+    // reconciled
+    fn track_withdraw_dust(
+        storage: &mut dyn Storage,
+        asset: &str,
+        dust: Uint128,
+    ) -> Result<(), ContractError> {
+        if dust.is_zero() {
+            return Ok(());
+        }
+        WITHDRAW_DUST.update(storage, asset.to_string(), |total| -> StdResult<_> {
+            Ok(total.unwrap_or_default().checked_add(dust)?)
+        })?;
+        Ok(())
+    }
+
     //internal function to transfer tokens from contract to account
     fn transfer_tokens(
         deps: DepsMut,
@@ -596,18 +675,12 @@ mod exec {
             ));
         }
 
-        let xcall = SOURCE_XCALL.load(deps.storage)?;
-        let protocol_config =
-            get_protocols(&deps.as_ref(), X_CALL_MANAGER.load(deps.storage)?).unwrap();
-        let fee: Uint128 = get_fee(
-            &deps.as_ref(),
-            xcall,
-            nid,
-            true,
-            Some(protocol_config.sources),
-        )
-        .unwrap()
-        .into();
+        let xcall = XCallClient::new(SOURCE_XCALL.load(deps.storage)?);
+        let protocol_config = XCallManagerClient::new(X_CALL_MANAGER.load(deps.storage)?)
+            .protocols(&deps.querier, nid.clone())?;
+        let fee: Uint128 = xcall
+            .fee(&deps.querier, nid, true, Some(protocol_config.sources))?
+            .into();
         ensure!(token.amount > fee, ContractError::InvalidAmount);
         let new_token = Coin {
             denom: token.denom.clone(),
@@ -639,6 +712,13 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetConfiguration {} => to_binary(&query::query_config(deps)?),
         QueryMsg::GetNetIds {} => to_binary(&query::query_nid(deps)?),
         QueryMsg::GetLimit { asset } => to_binary(&query::query_limit(deps, asset)?),
+        QueryMsg::GetAssetDecimals { asset } => {
+            to_binary(&query::query_asset_decimals(deps, asset)?)
+        }
+        QueryMsg::GetRecipientFormat { nid } => {
+            to_binary(&query::query_recipient_format(deps, nid)?)
+        }
+        QueryMsg::GetWithdrawDust { asset } => to_binary(&query::query_withdraw_dust(deps, asset)?),
     }
 }
 
@@ -676,24 +756,42 @@ mod query {
     pub fn query_limit(deps: Deps, asset: String) -> StdResult<RateLimit> {
         RATE_LIMITS.load(deps.storage, asset)
     }
+
+    pub fn query_asset_decimals(deps: Deps, asset: String) -> StdResult<u8> {
+        Ok(ASSET_DECIMALS
+            .may_load(deps.storage, asset)?
+            .unwrap_or(HUB_DECIMALS))
+    }
+
+    pub fn query_withdraw_dust(deps: Deps, asset: String) -> StdResult<Uint128> {
+        Ok(WITHDRAW_DUST
+            .may_load(deps.storage, asset)?
+            .unwrap_or_default())
+    }
+
+    pub fn query_recipient_format(deps: Deps, nid: String) -> StdResult<Option<AddressFormat>> {
+        let configured = RECIPIENT_FORMATS.may_load(deps.storage, nid.clone())?;
+        Ok(recipient_format(&nid, configured))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use cosmwasm_std::{
+        from_binary,
         testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier},
-        BlockInfo, Coin, ContractInfoResponse, ContractResult, MemoryStorage, OwnedDeps,
+        BlockInfo, Coin, ContractInfoResponse, ContractResult, CosmosMsg, MemoryStorage, OwnedDeps,
         SystemError, SystemResult, Uint128, WasmQuery,
     };
-    use cw_common::xcall_manager_msg::QueryMsg::GetProtocols;
-    use cw_xcall_multi::msg::QueryMsg::GetNetworkAddress;
+    use cw_common::xcall_client::mock::MockXCall;
 
     use cw_ibc_rlp_lib::rlp::Encodable;
     use std::vec;
     use tests::exec::verify_withdraw;
 
+    use cw_common::events::{DEPOSIT_REVERT, WITHDRAW, XCALL_CONFIGURED};
+    use cw_common::xcall_data_types::DepositRevert;
     use cw_common::{asset_manager_msg::InstantiateMsg, xcall_data_types::WithdrawTo};
-    use cw_common::{xcall_data_types::DepositRevert, xcall_manager_msg::ProtocolConfig};
 
     use super::*;
     const FEE: Uint128 = Uint128::new(10);
@@ -711,29 +809,16 @@ mod tests {
         let xcall = "xcall";
         let manager = "manager";
 
-        // mocking response for external query i.e. allowance
-        deps.querier.update_wasm(|r: &WasmQuery| match r {
-            WasmQuery::Smart { contract_addr, msg } => {
-                if contract_addr == &xcall.to_owned() {
-                    if msg == &to_binary(&GetNetworkAddress {}).unwrap() {
-                        return SystemResult::Ok(ContractResult::Ok(
-                            to_binary(&"0x44.archway/xcall".to_owned()).unwrap(),
-                        ));
-                    }
+        let mut xcall_mock = MockXCall::new(xcall, "0x44.archway/xcall", manager);
+        xcall_mock.fee = FEE.u128();
 
-                    SystemResult::Ok(ContractResult::Ok(to_binary(&FEE).unwrap()))
-                } else if contract_addr == &manager.to_owned() {
-                    if msg == &to_binary(&GetProtocols {}).unwrap() {
-                        return SystemResult::Ok(ContractResult::Ok(
-                            to_binary(&ProtocolConfig {
-                                sources: vec![],
-                                destinations: vec![],
-                            })
-                            .unwrap(),
-                        ));
-                    }
-                    SystemResult::Ok(ContractResult::Ok(to_binary(&true).unwrap()))
-                } else {
+        // mocking response for external query i.e. allowance
+        deps.querier.update_wasm(move |r: &WasmQuery| {
+            if let Some(res) = xcall_mock.query(r) {
+                return res;
+            }
+            match r {
+                WasmQuery::Smart { .. } => {
                     //mock allowance resp
                     let allowance_resp = AllowanceResponse {
                         allowance: Uint128::new(1000),
@@ -741,21 +826,21 @@ mod tests {
                     };
                     SystemResult::Ok(ContractResult::Ok(to_binary(&allowance_resp).unwrap()))
                 }
-            }
-            WasmQuery::ContractInfo {
-                contract_addr: addr,
-            } => {
-                if addr.starts_with("denom") || addr.eq("arch") {
-                    return SystemResult::Err(SystemError::NoSuchContract {
-                        addr: addr.to_string(),
-                    });
+                WasmQuery::ContractInfo {
+                    contract_addr: addr,
+                } => {
+                    if addr.starts_with("denom") || addr.eq("arch") {
+                        return SystemResult::Err(SystemError::NoSuchContract {
+                            addr: addr.to_string(),
+                        });
+                    }
+                    let mut response = ContractInfoResponse::default();
+                    response.code_id = 1;
+                    response.creator = "sender".to_string();
+                    SystemResult::Ok(ContractResult::Ok(to_binary(&response).unwrap()))
                 }
-                let mut response = ContractInfoResponse::default();
-                response.code_id = 1;
-                response.creator = "sender".to_string();
-                SystemResult::Ok(ContractResult::Ok(to_binary(&response).unwrap()))
+                _ => todo!(),
             }
-            _ => todo!(),
         });
 
         let instantiated_resp = instantiate(
@@ -811,12 +896,13 @@ mod tests {
         // Verify the event attributes
         if let Some(event) = response.events.get(0) {
             assert_eq!(event.ty, "Deposit");
-            assert_eq!(event.attributes.len(), 3);
+            assert_eq!(event.attributes.len(), 4);
 
             // Verify the individual event attributes
             for attribute in &event.attributes {
                 match attribute.key.as_str() {
                     "Token" => assert_eq!(attribute.value, "token1"),
+                    "From" => assert_eq!(attribute.value, "user"),
                     "To" => assert_eq!(attribute.value, "0x44.archway/user"),
                     "Amount" => assert_eq!(attribute.value, "100"),
                     _ => panic!("Unexpected attribute key"),
@@ -840,6 +926,7 @@ mod tests {
         for attribute in &result.events[0].attributes {
             match attribute.key.as_str() {
                 "Token" => assert_eq!(attribute.value, "token1"),
+                "From" => assert_eq!(attribute.value, "user"),
                 "To" => println!("value: {:?}", attribute.value),
                 "Amount" => assert_eq!(attribute.value, "100"),
                 _ => panic!("Unexpected attribute key"),
@@ -919,12 +1006,13 @@ mod tests {
         // Verify the event attributes
         if let Some(event) = response.events.get(0) {
             assert_eq!(event.ty, "Deposit");
-            assert_eq!(event.attributes.len(), 3);
+            assert_eq!(event.attributes.len(), 4);
 
             // Verify the individual event attributes
             for attribute in &event.attributes {
                 match attribute.key.as_str() {
                     "Token" => assert_eq!(attribute.value, denom),
+                    "From" => assert_eq!(attribute.value, "user"),
                     "To" => assert_eq!(attribute.value, "0x44.archway/user"),
                     "Amount" => assert_eq!(attribute.value, "100"),
                     _ => panic!("Unexpected attribute key"),
@@ -957,12 +1045,13 @@ mod tests {
         // Verify the event attributes
         if let Some(event) = response.events.get(0) {
             assert_eq!(event.ty, "Deposit");
-            assert_eq!(event.attributes.len(), 3);
+            assert_eq!(event.attributes.len(), 4);
 
             // Verify the individual event attributes
             for attribute in &event.attributes {
                 match attribute.key.as_str() {
                     "Token" => assert_eq!(attribute.value, "arch".to_string()),
+                    "From" => assert_eq!(attribute.value, "user"),
                     "To" => assert_eq!(attribute.value, "0x44.archway/user"),
                     "Amount" => assert_eq!(attribute.value, "100"),
                     _ => panic!("Unexpected attribute key"),
@@ -1000,6 +1089,7 @@ mod tests {
         //check for valid xcall expected msg data
 
         assert!(result.is_ok());
+        assert_eq!(result.unwrap().events[0].ty, DEPOSIT_REVERT);
 
         //for withdrawTo
         let am_nw = "0x01.icon/cxc2d01de5013778d71d99f985e4e2ff3a9b48a66c";
@@ -1021,6 +1111,7 @@ mod tests {
             exe_msg,
         );
         assert!(resp.is_ok());
+        assert_eq!(resp.unwrap().events[0].ty, WITHDRAW);
 
         //----------------------------------------------//
         //check for unhandled xcall msg data
@@ -1045,6 +1136,138 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_asset_decimals() {
+        let (mut deps, env, info, _) = test_setup();
+        let token = "token1";
+
+        let configure = ExecuteMsg::ConfigureAssetDecimals {
+            asset: token.to_string(),
+            decimals: 6,
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("other_sender", &[]),
+            configure.clone(),
+        );
+        assert_eq!(res.unwrap_err(), ContractError::OnlyOwner);
+        execute(deps.as_mut(), env.clone(), info.clone(), configure).unwrap();
+
+        let decimals: u8 = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::GetAssetDecimals {
+                    asset: token.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(decimals, 6);
+
+        // deposits are scaled up to the hub's 18 decimals
+        let msg = ExecuteMsg::Deposit {
+            token_address: token.to_string(),
+            amount: Uint128::new(100),
+            to: None,
+            data: None,
+        };
+        let response = execute(deps.as_mut(), env.clone(), info, msg).unwrap();
+        let expected = Deposit {
+            token_address: token.to_string(),
+            from: "user".to_string(),
+            to: "0x44.archway/user".to_string(),
+            amount: 100_000_000_000_000,
+            data: vec![],
+        };
+        match &response.messages[1].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                match from_binary::<XCallMsg>(msg).unwrap() {
+                    XCallMsg::SendCallMessage { data, .. } => {
+                        assert_eq!(data, expected.rlp_bytes().to_vec())
+                    }
+                    _ => panic!("Unexpected xcall message"),
+                }
+            }
+            _ => panic!("Unexpected message"),
+        }
+
+        // withdrawals are rounded down to the asset's 6 decimals
+        let mocked_xcall_info = mock_info("xcall", &[]);
+        let am_nw = "0x01.icon/cxc2d01de5013778d71d99f985e4e2ff3a9b48a66c";
+        let withdraw = |amount: u128| ExecuteMsg::HandleCallMessage {
+            from: am_nw.to_string(),
+            data: WithdrawTo {
+                token_address: token.to_string(),
+                amount,
+                user_address: "account1".to_string(),
+            }
+            .rlp_bytes()
+            .to_vec(),
+            protocols: None,
+        };
+
+        let response = execute(
+            deps.as_mut(),
+            env.clone(),
+            mocked_xcall_info.clone(),
+            withdraw(1_999_999_999_999),
+        )
+        .unwrap();
+        let expected = to_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: "account1".to_string(),
+            amount: Uint128::new(1),
+        })
+        .unwrap();
+        match &response.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => assert_eq!(msg, &expected),
+            _ => panic!("Unexpected message"),
+        }
+
+        // the remainder below the asset's precision is reported and tracked per asset
+        let withdraw_event = response
+            .events
+            .iter()
+            .find(|event| event.ty == "Withdraw")
+            .unwrap();
+        let dust = withdraw_event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "dust")
+            .unwrap();
+        assert_eq!(dust.value, "999999999999");
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mocked_xcall_info.clone(),
+            withdraw(1_000_000_000_001),
+        )
+        .unwrap();
+        let dust: Uint128 = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::GetWithdrawDust {
+                    asset: token.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(dust, Uint128::new(1_000_000_000_000));
+
+        // amounts entirely below the asset's precision cannot be withdrawn
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mocked_xcall_info,
+            withdraw(999_999_999_999),
+        );
+        assert_eq!(res.unwrap_err(), ContractError::InvalidAmount);
+    }
+
     #[test]
     fn test_withdraw_denom() {
         let (mut deps, env, _, _) = test_setup();
@@ -1290,6 +1513,76 @@ mod tests {
         assert!(resp.is_ok());
     }
 
+    #[test]
+    fn test_recipient_validation() {
+        let (mut deps, env, owner, _) = test_setup();
+        let info = mock_info(
+            "user",
+            &[Coin {
+                denom: "arch".to_string(),
+                amount: Uint128::new(100) + FEE,
+            }],
+        );
+        let deposit = |to: &str| ExecuteMsg::DepositDenom {
+            denom: "arch".to_string(),
+            to: Some(to.to_string()),
+            data: None,
+        };
+
+        // typo in the eip-55 checksum
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            deposit("0x1.eth/0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+        );
+        assert_eq!(res.unwrap_err(), ContractError::InvalidRecipientAddress);
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            deposit("0x1.eth/0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+        )
+        .unwrap();
+
+        // chains without a known or configured format are rejected
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            deposit("0x1.unknown/0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+        );
+        assert_eq!(res.unwrap_err(), ContractError::InvalidRecipientAddress);
+
+        // wrong bech32 prefix until the nid is configured for it
+        let recipient = "injective-1/archway1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc52fs6vt";
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), deposit(recipient));
+        assert_eq!(res.unwrap_err(), ContractError::InvalidRecipientAddress);
+
+        let format = AddressFormat::Bech32 {
+            prefix: "archway".to_string(),
+        };
+        let configure = ExecuteMsg::ConfigureRecipientFormat {
+            nid: "injective-1".to_string(),
+            format: Some(format.clone()),
+        };
+        let not_owner = mock_info("not_owner", &[]);
+        let res = execute(deps.as_mut(), env.clone(), not_owner, configure.clone());
+        assert_eq!(res.unwrap_err(), ContractError::OnlyOwner);
+        execute(deps.as_mut(), env.clone(), owner, configure).unwrap();
+
+        let res = query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::GetRecipientFormat {
+                nid: "injective-1".to_string(),
+            },
+        );
+        let configured: Option<AddressFormat> = from_binary(&res.unwrap()).unwrap();
+        assert_eq!(configured, Some(format));
+        execute(deps.as_mut(), env, info, deposit(recipient)).unwrap();
+    }
+
     #[test]
     fn test_configure_network() {
         //verify configuration updates from owner side
@@ -1310,7 +1603,8 @@ mod tests {
         // Check the response
         assert!(res.is_ok());
         let response: Response = res.unwrap();
-        assert_eq!(response, Response::default());
+        assert_eq!(response.messages.len(), 0);
+        assert_eq!(response.events[0].ty, XCALL_CONFIGURED);
 
         // Verify the saved values
         let saved_source_xcall: String = SOURCE_XCALL
@@ -1,9 +1,9 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Uint128};
 use cw_common::rate_limit::RateLimit;
 use cw_storage_plus::Item;
 use cw_storage_plus::Map;
 
-use cw_common::network_address::{NetId, NetworkAddress};
+use cw_common::network_address::{AddressFormat, NetId, NetworkAddress};
 
 pub const OWNER: Item<Addr> = Item::new("contract_owner");
 
@@ -20,3 +20,10 @@ pub const NATIVE_TOKEN_MANAGER: Item<Addr> = Item::new("native_token_manager");
 pub const X_CALL_MANAGER: Item<Addr> = Item::new("xcall_manager");
 
 pub const RATE_LIMITS: Map<String, RateLimit> = Map::new("rate_limits");
+
+pub const ASSET_DECIMALS: Map<String, u8> = Map::new("asset_decimals");
+
+// hub amounts below an asset's precision that withdrawals did not pay out, in hub units
+pub const WITHDRAW_DUST: Map<String, Uint128> = Map::new("withdraw_dust");
+
+pub const RECIPIENT_FORMATS: Map<String, AddressFormat> = Map::new("recipient_formats");
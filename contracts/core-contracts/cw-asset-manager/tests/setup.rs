@@ -233,6 +233,7 @@ pub fn init_xcall_manager(mut ctx: TestContext) -> TestContext {
                     sources: vec![ctx.get_xcall_connection().to_string()],
                     destinations: vec![],
                 },
+                timelock: None,
             },
             &[],
             "IbcCore",
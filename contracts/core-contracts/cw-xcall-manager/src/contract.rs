@@ -1,26 +1,47 @@
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::hash::Hash;
 
-use cosmwasm_std::{entry_point, CosmosMsg, QuerierWrapper};
+use cosmwasm_std::{entry_point, CosmosMsg, Empty, Order, QuerierWrapper, Storage};
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg, WasmMsg,
+    to_binary, Addr, BankMsg, Binary, Deps, DepsMut, Env, HexBinary, MessageInfo, Response,
+    StdError, StdResult, SubMsg, WasmMsg,
 };
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+use cw_utils::Expiration;
+use sha2::{Digest, Sha256};
 
+use cw_common::events::{governance_action_executed_event, protocols_configured_event};
+use cw_common::messages::HubMessage;
+use cw_common::network_address::address_format;
 use cw_common::xcall_manager_msg::{
-    ConfigureProtocols, Execute, ExecuteMsg, InstantiateMsg, Migrate, MigrateMsg, ProtocolConfig,
-    QueryMsg, UpdateAdmin, CONFIGURE_PROTOCOLS, EXECUTE, MIGRATE, UPDATE_ADMIN,
+    ConfigResponse, ConfigureProtocols, ConfigureRemovalQuorum, ConfigureRoles, ConfigureTimelock,
+    ExecuteMsg, GovernanceAction, InstantiateMsg, MigrateMsg, PendingActionResponse,
+    ProposerResponse, ProtocolConfig, QueryMsg, QueuedActionResponse, WhitelistedActionResponse,
+    BANK_SEND, BATCH, CONFIGURE_PROTOCOLS, CONFIGURE_REMOVAL_QUORUM, CONFIGURE_ROLES,
+    CONFIGURE_TIMELOCK, EXECUTE, MIGRATE, UPDATE_ADMIN,
 };
 use cw_ibc_rlp_lib::rlp::decode;
 use cw_ibc_rlp_lib::rlp::Rlp;
 
 use crate::error::ContractError;
+use crate::events::{
+    action_approved_event, action_consumed_event, action_queued_event, action_removed_event,
+    action_vetoed_event, action_whitelisted_event, proposer_changed_event,
+    removal_proposal_cleared_event, removal_proposed_event, removal_quorum_configured_event,
+    roles_configured_event, timelock_configured_event,
+};
 use crate::state::*;
 
 // version info for migration info
 const CONTRACT_NAME: &str = "crates.io:cw-asset-manager";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+// lifetime of actions carried over from the legacy whitelist, which had no expiry
+const MIGRATED_ACTION_EXPIRY: u64 = 7 * 24 * 60 * 60;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -30,7 +51,8 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
         .map_err(ContractError::Std)?;
-    PROPOSER.save(deps.storage, &info.sender)?;
+    PROPOSERS.save(deps.storage, &info.sender, &Empty {})?;
+    PROPOSER_THRESHOLD.save(deps.storage, &1)?;
     X_CALL
         .save(deps.storage, &msg.xcall.to_string())
         .map_err(ContractError::Std)?;
@@ -38,15 +60,18 @@ pub fn instantiate(
         .save(deps.storage, &msg.icon_governance)
         .map_err(ContractError::Std)?;
     PROTOCOLS
-        .save(deps.storage, &msg.protocols)
+        .save(deps.storage, &governance_nid(deps.storage)?, &msg.protocols)
         .map_err(ContractError::Std)?;
+    if let Some(timelock) = msg.timelock {
+        TIMELOCK.save(deps.storage, &timelock)?;
+    }
     Ok(Response::new())
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
@@ -55,61 +80,177 @@ pub fn execute(
             from,
             data,
             protocols,
-        } => handle_call_message(deps, info, from, data, protocols),
+        } => handle_call_message(deps, env, info, from, data, protocols),
         ExecuteMsg::ProposeChange { protocol } => {
-            let proposer = PROPOSER.load(deps.storage)?;
-            if info.sender != proposer {
-                return Err(ContractError::OnlyProposer);
+            if !GUARDIANS.has(deps.storage, &info.sender) {
+                ensure_proposer(deps.storage, &info.sender)?;
             }
 
-            PROPOSED_REMOVAL
-                .save(deps.storage, &protocol)
+            PROPOSED_REMOVALS
+                .save(deps.storage, &protocol, &Empty {})
                 .map_err(ContractError::Std)?;
 
-            Ok(Response::new())
+            Ok(Response::new().add_event(removal_proposed_event(&protocol, &info.sender)))
         }
-        ExecuteMsg::RemoveProposal {} => {
-            let proposer = PROPOSER.load(deps.storage)?;
-            if info.sender != proposer {
-                return Err(ContractError::OnlyProposer);
-            }
+        ExecuteMsg::RemoveProposal { protocol } => {
+            ensure_proposer(deps.storage, &info.sender)?;
 
-            PROPOSED_REMOVAL.remove(deps.storage);
+            match &protocol {
+                Some(protocol) => PROPOSED_REMOVALS.remove(deps.storage, protocol),
+                None => clear_proposed_removals(deps.storage)?,
+            }
 
-            Ok(Response::new())
+            Ok(Response::new().add_event(removal_proposal_cleared_event(protocol.as_deref())))
         }
         ExecuteMsg::ChangeProposer { proposer } => {
-            let current_proposer = PROPOSER.load(deps.storage)?;
-            if info.sender != current_proposer {
-                return Err(ContractError::OnlyProposer);
+            // hands the sender's seat over, the threshold is unchanged so the set must not shrink
+            ensure_proposer(deps.storage, &info.sender)?;
+            let proposer = deps.api.addr_validate(proposer.as_str())?;
+            if PROPOSERS.has(deps.storage, &proposer) {
+                return Err(ContractError::AlreadyProposer { proposer });
             }
 
-            PROPOSER.save(deps.storage, &proposer)?;
-            Ok(Response::new())
+            PROPOSERS.remove(deps.storage, &info.sender);
+            PROPOSERS.save(deps.storage, &proposer, &Empty {})?;
+            Ok(Response::new().add_event(proposer_changed_event(&info.sender, &proposer)))
         }
-        ExecuteMsg::WhitelistAction { action } => {
-            let current_proposer = PROPOSER.load(deps.storage)?;
-            if info.sender != current_proposer {
-                return Err(ContractError::OnlyProposer);
-            }
-
-            WHITELISTED_ACTIONS.save(deps.storage, action, &true)?;
-            Ok(Response::new())
+        ExecuteMsg::WhitelistAction { action, expiry } => {
+            ensure_proposer(deps.storage, &info.sender)?;
+            whitelist_action(deps, info.sender, action, expiry)
         }
         ExecuteMsg::RemoveAction { action } => {
-            let current_proposer = PROPOSER.load(deps.storage)?;
-            if info.sender != current_proposer {
-                return Err(ContractError::OnlyProposer);
+            ensure_proposer(deps.storage, &info.sender)?;
+
+            let hash = action_hash(&action);
+            WHITELISTED_ACTIONS.remove(deps.storage, &hash);
+            ACTION_APPROVALS.remove(deps.storage, &hash);
+            Ok(Response::new().add_event(action_removed_event(&hash)))
+        }
+        ExecuteMsg::ExecuteQueued { id } => execute_queued(deps, env, id),
+        ExecuteMsg::VetoQueued { id } => {
+            if !GUARDIANS.has(deps.storage, &info.sender) {
+                ensure_proposer(deps.storage, &info.sender)?;
             }
 
-            WHITELISTED_ACTIONS.remove(deps.storage, action);
-            Ok(Response::new())
+            if !QUEUED_ACTIONS.has(deps.storage, id) {
+                return Err(ContractError::QueuedActionNotFound { id });
+            }
+            QUEUED_ACTIONS.remove(deps.storage, id);
+            Ok(Response::new().add_event(action_vetoed_event(id, &info.sender)))
         }
     }
 }
 
+fn ensure_proposer(storage: &dyn Storage, sender: &Addr) -> Result<(), ContractError> {
+    if !PROPOSERS.has(storage, sender) {
+        return Err(ContractError::OnlyProposer);
+    }
+    Ok(())
+}
+
+fn whitelist_action(
+    deps: DepsMut,
+    proposer: Addr,
+    action: Vec<u8>,
+    expiry: Option<Expiration>,
+) -> Result<Response, ContractError> {
+    let hash = action_hash(&action);
+    let expiry = expiry.unwrap_or_default();
+    let mut pending = ACTION_APPROVALS
+        .may_load(deps.storage, &hash)?
+        .unwrap_or(PendingAction {
+            approvals: vec![],
+            expiry,
+        });
+    // approvals are for the action as proposed, including its expiry
+    if expiry != pending.expiry {
+        return Err(ContractError::ActionParametersMismatch {
+            expiry,
+            proposed: pending.expiry,
+        });
+    }
+    if !pending.approvals.contains(&proposer) {
+        pending.approvals.push(proposer.clone());
+    }
+
+    // approvals of proposers that have since been removed no longer count
+    pending
+        .approvals
+        .retain(|approver| PROPOSERS.has(deps.storage, approver));
+    let threshold = PROPOSER_THRESHOLD.load(deps.storage)?;
+    if pending.approvals.len() < threshold as usize {
+        ACTION_APPROVALS.save(deps.storage, &hash, &pending)?;
+        return Ok(Response::new().add_event(action_approved_event(
+            &hash,
+            &proposer,
+            pending.approvals.len(),
+            threshold,
+            &pending.expiry,
+        )));
+    }
+
+    ACTION_APPROVALS.remove(deps.storage, &hash);
+    let whitelisted = WhitelistedAction {
+        proposer: pending.approvals[0].clone(),
+        expiry: pending.expiry,
+    };
+    WHITELISTED_ACTIONS.save(deps.storage, &hash, &whitelisted)?;
+    Ok(Response::new().add_event(action_whitelisted_event(
+        &hash,
+        &whitelisted.proposer,
+        &whitelisted.expiry,
+    )))
+}
+
+fn configure_roles(deps: DepsMut, roles: ConfigureRoles) -> Result<Response, ContractError> {
+    let proposers = roles
+        .proposers
+        .iter()
+        .map(|proposer| deps.api.addr_validate(proposer))
+        .collect::<StdResult<BTreeSet<Addr>>>()?;
+    let guardians = roles
+        .guardians
+        .iter()
+        .map(|guardian| deps.api.addr_validate(guardian))
+        .collect::<StdResult<BTreeSet<Addr>>>()?;
+    if roles.threshold == 0 || roles.threshold as usize > proposers.len() {
+        return Err(ContractError::InvalidThreshold {
+            threshold: roles.threshold,
+            proposers: proposers.len() as u32,
+        });
+    }
+
+    let current = PROPOSERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for proposer in current {
+        PROPOSERS.remove(deps.storage, &proposer);
+    }
+    let current = GUARDIANS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+    for guardian in current {
+        GUARDIANS.remove(deps.storage, &guardian);
+    }
+
+    for proposer in &proposers {
+        PROPOSERS.save(deps.storage, proposer, &Empty {})?;
+    }
+    for guardian in &guardians {
+        GUARDIANS.save(deps.storage, guardian, &Empty {})?;
+    }
+    PROPOSER_THRESHOLD.save(deps.storage, &roles.threshold)?;
+
+    Ok(Response::new().add_event(roles_configured_event(
+        proposers.len(),
+        roles.threshold,
+        guardians.len(),
+    )))
+}
+
 pub fn handle_call_message(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     from: String,
     data: Vec<u8>,
@@ -126,88 +267,220 @@ pub fn handle_call_message(
     }
 
     let rlp: Rlp = Rlp::new(&data);
-    let method: String = rlp.val_at(0).unwrap();
+    let method: String = rlp.val_at(0)?;
     let protocols_list = if let Some(x) = protocols { x } else { vec![] };
 
-    let res = verify_protocols(&deps, protocols_list, method.to_string());
-    if res.is_err() {
-        return Err(res.err().unwrap());
+    verify_protocols(&deps, protocols_list, method.to_string())?;
+
+    let hash = action_hash(&data);
+    let whitelisted = WHITELISTED_ACTIONS
+        .may_load(deps.storage, &hash)?
+        .ok_or(ContractError::ActionNotWhitelisted)?;
+    if whitelisted.expiry.is_expired(&env.block) {
+        return Err(ContractError::ActionExpired);
     }
+    WHITELISTED_ACTIONS.remove(deps.storage, &hash);
+    let consumed = action_consumed_event(&hash, &method);
+
+    // configuration goes through the timelock like any other action, so guardians get the same
+    // window to veto a protocol or role change
+    let message = decode_message(&method, &data)?;
+    let response = match TIMELOCK.may_load(deps.storage)? {
+        Some(timelock) => queue_action(deps.storage, &env, &method, data, timelock)?,
+        None => apply_message(deps, message, None)?,
+    };
+    Ok(response.add_event(consumed))
+}
 
-    let whitelisted = WHITELISTED_ACTIONS.load(deps.storage, data.clone());
-    if whitelisted.is_err() || !whitelisted.unwrap() {
-        return Err(ContractError::ActionNotWhitelisted);
+fn apply_message(
+    deps: DepsMut,
+    message: HubMessage,
+    id: Option<u64>,
+) -> Result<Response, ContractError> {
+    let executed = governance_action_executed_event(message.method(), id);
+    let response = match message {
+        HubMessage::ConfigureProtocols(configure_protocols) => {
+            configure_protocols_route(deps, configure_protocols)?
+        }
+        HubMessage::ConfigureRemovalQuorum(configure_quorum) => {
+            configure_removal_quorum(deps.storage, configure_quorum)?
+        }
+        HubMessage::ConfigureRoles(roles) => configure_roles(deps, roles)?,
+        HubMessage::ConfigureTimelock(configure_timelock) => {
+            configure_timelock(deps.storage, configure_timelock)?
+        }
+        HubMessage::Governance(action) => Response::new().add_submessages(action_messages(action)?),
+        _ => return Err(ContractError::InvalidMethod),
+    };
+    Ok(response.add_event(executed))
+}
+
+fn configure_protocols_route(
+    deps: DepsMut,
+    configure_protocols: ConfigureProtocols,
+) -> Result<Response, ContractError> {
+    let governance_nid = governance_nid(deps.storage)?;
+    let nid = configure_protocols
+        .nid
+        .unwrap_or_else(|| governance_nid.clone());
+    let cfg = ProtocolConfig {
+        sources: configure_protocols.sources,
+        destinations: configure_protocols.destinations,
+    };
+    validate_protocols(&nid, &cfg)?;
+    for address in &cfg.sources {
+        if !is_contract(deps.querier, &Addr::unchecked(address)) {
+            return Err(ContractError::InvalidProtocol);
+        }
     }
-    WHITELISTED_ACTIONS.remove(deps.storage, data.clone());
 
-    match method.as_str() {
-        CONFIGURE_PROTOCOLS => {
-            let configure_protocols: ConfigureProtocols = decode(&data).unwrap();
-            for address in &configure_protocols.sources {
-                if !is_contract(deps.querier, &Addr::unchecked(address)) {
-                    return Err(ContractError::InvalidProtocol);
-                }
+    let old = PROTOCOLS.may_load(deps.storage, &nid)?;
+    PROTOCOLS
+        .save(deps.storage, &nid, &cfg)
+        .map_err(ContractError::Std)?;
+    let mut response = Response::new().add_event(protocols_configured_event(&nid, old, &cfg));
+    if nid == governance_nid {
+        clear_proposed_removals(deps.storage)?;
+        // a quorum above the new source count would make removals impossible
+        let sources = cfg.sources.len() as u32;
+        if let Some(quorum) = REMOVAL_QUORUM.may_load(deps.storage)? {
+            if quorum > sources {
+                REMOVAL_QUORUM.save(deps.storage, &sources)?;
+                response = response.add_event(removal_quorum_configured_event(sources));
             }
+        }
+    }
+    Ok(response)
+}
 
-            let cfg = ProtocolConfig {
-                sources: configure_protocols.sources,
-                destinations: configure_protocols.destinations,
-            };
+fn configure_removal_quorum(
+    storage: &mut dyn Storage,
+    configure_quorum: ConfigureRemovalQuorum,
+) -> Result<Response, ContractError> {
+    let sources = governance_protocols(storage)?.sources.len() as u32;
+    if configure_quorum.quorum == 0 || configure_quorum.quorum > sources {
+        return Err(ContractError::InvalidQuorum {
+            quorum: configure_quorum.quorum,
+            sources,
+        });
+    }
 
-            PROTOCOLS
-                .save(deps.storage, &cfg)
-                .map_err(ContractError::Std)?;
-            PROPOSED_REMOVAL.remove(deps.storage);
-            Ok(Response::new())
-        }
-        EXECUTE => {
-            let execute: Execute = decode(&data).unwrap();
-            let wasm_execute_message: CosmosMsg = CosmosMsg::Wasm(WasmMsg::Execute {
-                contract_addr: execute.contract_addr,
-                msg: Binary::from_base64(&execute.message)?,
-                funds: vec![],
-            });
+    let quorum = configure_quorum.quorum;
+    REMOVAL_QUORUM.save(storage, &quorum)?;
+    Ok(Response::new().add_event(removal_quorum_configured_event(quorum)))
+}
 
-            let sub_message = SubMsg::new(wasm_execute_message);
-            Ok(Response::new().add_submessage(sub_message))
-        }
-        MIGRATE => {
-            let migrate: Migrate = decode(&data).unwrap();
-            let wasm_execute_message: CosmosMsg = CosmosMsg::Wasm(WasmMsg::Migrate {
-                contract_addr: migrate.contract_addr,
-                new_code_id: migrate.code_id,
-                msg: Binary::from_base64(&migrate.message)?,
-            });
+// applies to actions queued from now on, already queued ones keep their eta
+fn configure_timelock(
+    storage: &mut dyn Storage,
+    configure_timelock: ConfigureTimelock,
+) -> Result<Response, ContractError> {
+    match configure_timelock.timelock {
+        0 => TIMELOCK.remove(storage),
+        timelock => TIMELOCK.save(storage, &timelock)?,
+    }
+    Ok(Response::new().add_event(timelock_configured_event(configure_timelock.timelock)))
+}
 
-            let sub_message = SubMsg::new(wasm_execute_message);
-            Ok(Response::new().add_submessage(sub_message))
-        }
-        UPDATE_ADMIN => {
-            let update_admin: UpdateAdmin = decode(&data).unwrap();
-            let wasm_execute_message: CosmosMsg = CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
-                contract_addr: update_admin.contract_addr,
-                admin: update_admin.admin,
-            });
+pub fn action_hash(action: &[u8]) -> Vec<u8> {
+    Sha256::digest(action).to_vec()
+}
 
-            let sub_message = SubMsg::new(wasm_execute_message);
-            Ok(Response::new().add_submessage(sub_message))
-        }
+// the governance messages handled here, the rest of the hub messages belong to other contracts
+fn decode_message(method: &str, data: &[u8]) -> Result<HubMessage, ContractError> {
+    match method {
+        CONFIGURE_PROTOCOLS
+        | CONFIGURE_REMOVAL_QUORUM
+        | CONFIGURE_ROLES
+        | CONFIGURE_TIMELOCK
+        | EXECUTE
+        | MIGRATE
+        | UPDATE_ADMIN
+        | BANK_SEND
+        | BATCH => Ok(decode::<HubMessage>(data)?),
         _ => Err(ContractError::InvalidMethod),
     }
 }
 
+// batched actions are flattened into plain messages so they succeed or fail together
+fn action_messages(action: GovernanceAction) -> StdResult<Vec<SubMsg>> {
+    let msg: CosmosMsg = match action {
+        GovernanceAction::Execute(execute) => WasmMsg::Execute {
+            contract_addr: execute.contract_addr,
+            msg: Binary::from_base64(&execute.message)?,
+            funds: execute.funds.unwrap_or_default(),
+        }
+        .into(),
+        GovernanceAction::Migrate(migrate) => WasmMsg::Migrate {
+            contract_addr: migrate.contract_addr,
+            new_code_id: migrate.code_id,
+            msg: Binary::from_base64(&migrate.message)?,
+        }
+        .into(),
+        GovernanceAction::UpdateAdmin(update_admin) => WasmMsg::UpdateAdmin {
+            contract_addr: update_admin.contract_addr,
+            admin: update_admin.admin,
+        }
+        .into(),
+        GovernanceAction::BankSend(bank_send) => BankMsg::Send {
+            to_address: bank_send.to_address,
+            amount: bank_send.amount,
+        }
+        .into(),
+        GovernanceAction::Batch(batch) => {
+            let mut messages = vec![];
+            for action in batch.actions {
+                messages.extend(action_messages(action)?);
+            }
+            return Ok(messages);
+        }
+    };
+    Ok(vec![SubMsg::new(msg)])
+}
+
+fn queue_action(
+    storage: &mut dyn Storage,
+    env: &Env,
+    method: &str,
+    data: Vec<u8>,
+    timelock: u64,
+) -> Result<Response, ContractError> {
+    let id = NEXT_ACTION_ID.may_load(storage)?.unwrap_or_default();
+    let eta = env.block.time.plus_seconds(timelock);
+    QUEUED_ACTIONS.save(storage, id, &QueuedAction { data, eta })?;
+    NEXT_ACTION_ID.save(storage, &(id + 1))?;
+
+    Ok(Response::new().add_event(action_queued_event(id, method, eta)))
+}
+
+fn decode_queued_action(queued: &QueuedAction) -> Result<HubMessage, ContractError> {
+    let method: String = Rlp::new(&queued.data).val_at(0)?;
+    decode_message(&method, &queued.data)
+}
+
+fn execute_queued(deps: DepsMut, env: Env, id: u64) -> Result<Response, ContractError> {
+    let queued = QUEUED_ACTIONS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::QueuedActionNotFound { id })?;
+    if env.block.time < queued.eta {
+        return Err(ContractError::QueuedActionNotReady {
+            id,
+            eta: queued.eta.seconds(),
+        });
+    }
+    QUEUED_ACTIONS.remove(deps.storage, id);
+    let message = decode_queued_action(&queued)?;
+    apply_message(deps, message, Some(id))
+}
+
 fn verify_protocols(
     deps: &DepsMut,
     protocols: Vec<String>,
     method: String,
 ) -> Result<(), ContractError> {
-    let allowed_protocols = PROTOCOLS.load(deps.storage);
-    println!("{:?}", allowed_protocols);
-    if allowed_protocols.is_err() {
-        return Err(ContractError::NetworkNotConfigured);
-    }
-
-    let allowed_protocols = allowed_protocols?.sources;
+    let allowed_protocols = governance_protocols(deps.storage)
+        .map_err(|_| ContractError::NetworkNotConfigured)?
+        .sources;
     if array_eq(&allowed_protocols, &protocols) {
         return Ok(());
     }
@@ -216,20 +489,98 @@ fn verify_protocols(
         return Err(ContractError::InvalidProtocol);
     }
 
-    let proposed_protocol_to_remove = PROPOSED_REMOVAL.load(deps.storage)?;
-    let joined_protocols = {
-        let mut tmp = protocols;
-        tmp.push(proposed_protocol_to_remove);
-        tmp
-    };
+    // a protocol change may arrive without some current sources, as long as every missing
+    // source has been proposed for removal and the delivering sources reach the quorum.
+    // Without a configured quorum only a single source may be missing.
+    let delivered: HashSet<&String> = protocols.iter().collect();
+    if delivered
+        .iter()
+        .any(|protocol| !allowed_protocols.contains(protocol))
+    {
+        return Err(ContractError::InvalidProtocol);
+    }
+    for protocol in allowed_protocols.iter() {
+        if !delivered.contains(protocol) && !PROPOSED_REMOVALS.has(deps.storage, protocol) {
+            return Err(ContractError::InvalidProtocol);
+        }
+    }
 
-    if !array_eq(&joined_protocols, &allowed_protocols) {
+    let sources: HashSet<&String> = allowed_protocols.iter().collect();
+    let quorum = REMOVAL_QUORUM
+        .may_load(deps.storage)?
+        .map(|quorum| quorum as usize)
+        .unwrap_or(sources.len().saturating_sub(1));
+    if delivered.len() < quorum {
         return Err(ContractError::InvalidProtocol);
     }
 
     Ok(())
 }
 
+// governance messages are delivered over the route to the governance network
+fn governance_nid(storage: &dyn Storage) -> StdResult<String> {
+    let governance = ICON_GOVERNANCE.load(storage)?;
+    Ok(governance.split('/').next().unwrap_or_default().to_string())
+}
+
+fn governance_protocols(storage: &dyn Storage) -> StdResult<ProtocolConfig> {
+    PROTOCOLS.load(storage, &governance_nid(storage)?)
+}
+
+fn network_protocols(storage: &dyn Storage, nid: &str) -> StdResult<ProtocolConfig> {
+    match PROTOCOLS.may_load(storage, nid)? {
+        Some(protocols) => Ok(protocols),
+        None => governance_protocols(storage),
+    }
+}
+
+fn clear_proposed_removals(storage: &mut dyn Storage) -> StdResult<()> {
+    let proposed = PROPOSED_REMOVALS
+        .keys(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<String>>>()?;
+    for protocol in proposed {
+        PROPOSED_REMOVALS.remove(storage, &protocol);
+    }
+    Ok(())
+}
+
+fn validate_protocols(nid: &str, cfg: &ProtocolConfig) -> Result<(), ContractError> {
+    if cfg.sources.is_empty() {
+        return Err(ContractError::EmptySources);
+    }
+    for protocols in [&cfg.sources, &cfg.destinations] {
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = protocols.iter().find(|protocol| !seen.insert(*protocol)) {
+            return Err(ContractError::DuplicateProtocol {
+                protocol: duplicate.to_string(),
+            });
+        }
+    }
+
+    if let Some(destination) = cfg
+        .destinations
+        .iter()
+        .find(|destination| !is_valid_destination(nid, destination))
+    {
+        return Err(ContractError::InvalidDestination {
+            nid: nid.to_string(),
+            destination: destination.to_string(),
+        });
+    }
+    Ok(())
+}
+
+// destinations are connection contracts on the target chain, chains missing from the address
+// registry only get a sanity check
+fn is_valid_destination(nid: &str, destination: &str) -> bool {
+    match address_format(nid) {
+        Some(format) => format.validate(destination),
+        None => {
+            !destination.is_empty() && !destination.chars().any(|c| c.is_whitespace() || c == '/')
+        }
+    }
+}
+
 fn array_eq<T>(a: &[T], b: &[T]) -> bool
 where
     T: Eq + Hash,
@@ -245,25 +596,163 @@ pub fn is_contract(querier: QuerierWrapper, address: &Addr) -> bool {
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
         .map_err(ContractError::Std)?;
+    if let Some(protocol) = PROPOSED_REMOVAL.may_load(deps.storage)? {
+        PROPOSED_REMOVALS.save(deps.storage, &protocol, &Empty {})?;
+        PROPOSED_REMOVAL.remove(deps.storage);
+    }
+    if let Some(protocols) = LEGACY_PROTOCOLS.may_load(deps.storage)? {
+        PROTOCOLS.save(deps.storage, &governance_nid(deps.storage)?, &protocols)?;
+        LEGACY_PROTOCOLS.remove(deps.storage);
+    }
+    if let Some(protocols) = msg.protocols {
+        PROTOCOLS.save(deps.storage, &governance_nid(deps.storage)?, &protocols)?;
+        clear_proposed_removals(deps.storage)?;
+        REMOVAL_QUORUM.remove(deps.storage);
+    }
+    let legacy_proposer = PROPOSER.may_load(deps.storage)?;
+    let migrated_actions = migrate_whitelisted_actions(
+        deps.storage,
+        legacy_proposer.clone().unwrap_or(env.contract.address),
+        Expiration::AtTime(env.block.time.plus_seconds(MIGRATED_ACTION_EXPIRY)),
+    )?;
+    if let Some(proposer) = legacy_proposer {
+        PROPOSERS.save(deps.storage, &proposer, &Empty {})?;
+        PROPOSER_THRESHOLD.save(deps.storage, &1)?;
+        PROPOSER.remove(deps.storage);
+    }
 
-    Ok(Response::default().add_attribute("migrate", "successful"))
+    Ok(Response::default()
+        .add_attribute("migrate", "successful")
+        .add_attribute("migrated_actions", migrated_actions.to_string()))
+}
+
+// moves the raw action keys of earlier versions over to their hashes, returns how many were moved
+fn migrate_whitelisted_actions(
+    storage: &mut dyn Storage,
+    proposer: Addr,
+    expiry: Expiration,
+) -> StdResult<usize> {
+    let legacy = LEGACY_WHITELISTED_ACTIONS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut migrated = 0;
+    for (action, whitelisted) in legacy {
+        LEGACY_WHITELISTED_ACTIONS.remove(storage, action.clone());
+        if !whitelisted {
+            continue;
+        }
+        let whitelisted = WhitelistedAction {
+            proposer: proposer.clone(),
+            expiry,
+        };
+        WHITELISTED_ACTIONS.save(storage, &action_hash(&action), &whitelisted)?;
+        migrated += 1;
+    }
+    Ok(migrated)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::VerifyProtocols { protocols } => {
-            let allowed_protocols = PROTOCOLS.load(deps.storage);
+            let allowed_protocols = governance_protocols(deps.storage);
             if allowed_protocols.is_err() {
                 return to_binary(&false);
             }
 
-            to_binary(&(allowed_protocols?.sources == protocols))
+            to_binary(&array_eq(&allowed_protocols?.sources, &protocols))
         }
 
-        QueryMsg::GetProtocols {} => to_binary(&PROTOCOLS.load(deps.storage)?),
+        QueryMsg::GetProtocols { nid } => {
+            to_binary(&network_protocols(deps.storage, &nid.to_string())?)
+        }
+        QueryMsg::GetGovernance {} => to_binary(&ICON_GOVERNANCE.load(deps.storage)?),
+        QueryMsg::GetConfig {} => to_binary(&ConfigResponse {
+            xcall: X_CALL.load(deps.storage)?,
+            icon_governance: ICON_GOVERNANCE.load(deps.storage)?,
+            timelock: TIMELOCK.may_load(deps.storage)?,
+        }),
+        QueryMsg::GetProposedRemoval {} => to_binary(
+            &PROPOSED_REMOVALS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<String>>>()?,
+        ),
+        QueryMsg::GetRemovalQuorum {} => to_binary(&REMOVAL_QUORUM.may_load(deps.storage)?),
+        QueryMsg::GetProposer {} => to_binary(&ProposerResponse {
+            proposers: PROPOSERS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<_>>()?,
+            threshold: PROPOSER_THRESHOLD.load(deps.storage)?,
+            guardians: GUARDIANS
+                .keys(deps.storage, None, None, Order::Ascending)
+                .collect::<StdResult<_>>()?,
+        }),
+        QueryMsg::GetTimelock {} => to_binary(&TIMELOCK.may_load(deps.storage)?),
+        QueryMsg::GetQueuedActions { start_after, limit } => {
+            to_binary(&query_queued_actions(deps, start_after, limit)?)
+        }
+        QueryMsg::GetWhitelistedActions { start_after, limit } => {
+            to_binary(&query_whitelisted_actions(deps, start_after, limit)?)
+        }
+        QueryMsg::IsActionWhitelisted { action } => {
+            let whitelisted = WHITELISTED_ACTIONS.may_load(deps.storage, &action_hash(&action))?;
+            to_binary(&whitelisted.map_or(false, |w| !w.expiry.is_expired(&env.block)))
+        }
+        QueryMsg::GetPendingAction { action } => {
+            let pending = ACTION_APPROVALS.may_load(deps.storage, &action_hash(&action))?;
+            to_binary(&pending.map(|pending| PendingActionResponse {
+                approvals: pending.approvals,
+                expiry: pending.expiry,
+            }))
+        }
     }
 }
+
+fn query_whitelisted_actions(
+    deps: Deps,
+    start_after: Option<HexBinary>,
+    limit: Option<u32>,
+) -> StdResult<Vec<WhitelistedActionResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after
+        .as_ref()
+        .map(|hash| Bound::exclusive(hash.as_slice()));
+    WHITELISTED_ACTIONS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (hash, whitelisted) = item?;
+            Ok(WhitelistedActionResponse {
+                hash: hash.into(),
+                proposer: whitelisted.proposer,
+                expiry: whitelisted.expiry,
+            })
+        })
+        .collect()
+}
+
+fn query_queued_actions(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<QueuedActionResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+    QUEUED_ACTIONS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (id, queued) = item?;
+            let action =
+                decode_queued_action(&queued).map_err(|e| StdError::generic_err(e.to_string()))?;
+            Ok(QueuedActionResponse {
+                id,
+                eta: queued.eta,
+                action,
+            })
+        })
+        .collect()
+}
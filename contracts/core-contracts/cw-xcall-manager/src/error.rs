@@ -1,5 +1,6 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Addr, StdError};
 use cw_ibc_rlp_lib::rlp::DecoderError;
+use cw_utils::Expiration;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -11,6 +12,12 @@ pub enum ContractError {
     #[error("Only proposer is allowed")]
     OnlyProposer,
 
+    #[error("{proposer} is already a proposer")]
+    AlreadyProposer { proposer: Addr },
+
+    #[error("Invalid proposer threshold {threshold} for {proposers} proposers")]
+    InvalidThreshold { threshold: u32, proposers: u32 },
+
     #[error("Only governance is allowed")]
     OnlyGovernance,
 
@@ -26,9 +33,36 @@ pub enum ContractError {
     #[error("Invalid protocols")]
     InvalidProtocol,
 
+    #[error("At least one source protocol is required")]
+    EmptySources,
+
+    #[error("Duplicate protocol {protocol}")]
+    DuplicateProtocol { protocol: String },
+
+    #[error("Invalid destination {destination} for network {nid}")]
+    InvalidDestination { nid: String, destination: String },
+
+    #[error("Invalid removal quorum {quorum} for {sources} sources")]
+    InvalidQuorum { quorum: u32, sources: u32 },
+
     #[error("Action is not whitelisted")]
     ActionNotWhitelisted,
 
+    #[error("Whitelisted action has expired")]
+    ActionExpired,
+
+    #[error("Approval expiry {expiry} does not match the proposed expiry {proposed}")]
+    ActionParametersMismatch {
+        expiry: Expiration,
+        proposed: Expiration,
+    },
+
+    #[error("Queued action {id} not found")]
+    QueuedActionNotFound { id: u64 },
+
+    #[error("Queued action {id} is timelocked until {eta}")]
+    QueuedActionNotReady { id: u64, eta: u64 },
+
     #[error("Rlp Error: {error}")]
     DecoderError { error: DecoderError },
 }
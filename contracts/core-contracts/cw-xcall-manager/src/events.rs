@@ -0,0 +1,80 @@
+use cosmwasm_std::{Addr, Event, HexBinary, Timestamp};
+use cw_utils::Expiration;
+
+pub fn action_whitelisted_event(hash: &[u8], proposer: &Addr, expiry: &Expiration) -> Event {
+    Event::new("ActionWhitelisted")
+        .add_attribute("hash", HexBinary::from(hash).to_hex())
+        .add_attribute("proposer", proposer.to_string())
+        .add_attribute("expiry", expiry.to_string())
+}
+
+pub fn action_approved_event(
+    hash: &[u8],
+    proposer: &Addr,
+    approvals: usize,
+    threshold: u32,
+    expiry: &Expiration,
+) -> Event {
+    Event::new("ActionApproved")
+        .add_attribute("hash", HexBinary::from(hash).to_hex())
+        .add_attribute("proposer", proposer.to_string())
+        .add_attribute("approvals", approvals.to_string())
+        .add_attribute("threshold", threshold.to_string())
+        .add_attribute("expiry", expiry.to_string())
+}
+
+pub fn action_removed_event(hash: &[u8]) -> Event {
+    Event::new("ActionRemoved").add_attribute("hash", HexBinary::from(hash).to_hex())
+}
+
+pub fn action_consumed_event(hash: &[u8], method: &str) -> Event {
+    Event::new("ActionConsumed")
+        .add_attribute("hash", HexBinary::from(hash).to_hex())
+        .add_attribute("method", method)
+}
+
+pub fn removal_proposed_event(protocol: &str, proposer: &Addr) -> Event {
+    Event::new("ProtocolRemovalProposed")
+        .add_attribute("protocol", protocol)
+        .add_attribute("proposer", proposer.to_string())
+}
+
+// protocol is empty when every proposed removal was cleared
+pub fn removal_proposal_cleared_event(protocol: Option<&str>) -> Event {
+    Event::new("ProtocolRemovalCleared").add_attribute("protocol", protocol.unwrap_or_default())
+}
+
+pub fn proposer_changed_event(old: &Addr, new: &Addr) -> Event {
+    Event::new("ProposerChanged")
+        .add_attribute("old", old.to_string())
+        .add_attribute("new", new.to_string())
+}
+
+pub fn roles_configured_event(proposers: usize, threshold: u32, guardians: usize) -> Event {
+    Event::new("RolesConfigured")
+        .add_attribute("proposers", proposers.to_string())
+        .add_attribute("threshold", threshold.to_string())
+        .add_attribute("guardians", guardians.to_string())
+}
+
+pub fn removal_quorum_configured_event(quorum: u32) -> Event {
+    Event::new("RemovalQuorumConfigured").add_attribute("quorum", quorum.to_string())
+}
+
+// a zero timelock means actions are executed on delivery
+pub fn timelock_configured_event(timelock: u64) -> Event {
+    Event::new("TimelockConfigured").add_attribute("timelock", timelock.to_string())
+}
+
+pub fn action_queued_event(id: u64, method: &str, eta: Timestamp) -> Event {
+    Event::new("ActionQueued")
+        .add_attribute("action_id", id.to_string())
+        .add_attribute("method", method)
+        .add_attribute("eta", eta.seconds().to_string())
+}
+
+pub fn action_vetoed_event(id: u64, sender: &Addr) -> Event {
+    Event::new("ActionVetoed")
+        .add_attribute("action_id", id.to_string())
+        .add_attribute("sender", sender.to_string())
+}
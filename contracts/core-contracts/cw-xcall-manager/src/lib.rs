@@ -1,4 +1,5 @@
 pub mod contract;
 mod error;
+pub mod events;
 pub mod state;
 pub use crate::error::ContractError;
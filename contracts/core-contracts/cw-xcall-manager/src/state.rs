@@ -1,11 +1,52 @@
-use cosmwasm_std::Addr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty, Timestamp};
 use cw_common::xcall_manager_msg::ProtocolConfig;
 use cw_storage_plus::{Item, Map};
+use cw_utils::Expiration;
 
 pub const X_CALL: Item<String> = Item::new("xcall_address");
 pub const ICON_GOVERNANCE: Item<String> = Item::new("icon_governance_network_address");
+// single proposer of earlier versions, moved into PROPOSERS on migration
 pub const PROPOSER: Item<Addr> = Item::new("admin_wallet");
-pub const PROTOCOLS: Item<ProtocolConfig> = Item::new("protocols");
+pub const PROPOSERS: Map<&Addr, Empty> = Map::new("proposers");
+pub const PROPOSER_THRESHOLD: Item<u32> = Item::new("proposer_threshold");
+pub const GUARDIANS: Map<&Addr, Empty> = Map::new("guardians");
+// single protocol config of earlier versions, moved under the governance network on migration
+pub const LEGACY_PROTOCOLS: Item<ProtocolConfig> = Item::new("protocols");
+// protocol config per destination network id
+pub const PROTOCOLS: Map<&str, ProtocolConfig> = Map::new("network_protocols");
+// single proposed removal of earlier versions, moved into PROPOSED_REMOVALS on migration
 pub const PROPOSED_REMOVAL: Item<String> = Item::new("proposed_removal");
+pub const PROPOSED_REMOVALS: Map<&str, Empty> = Map::new("proposed_removals");
+pub const REMOVAL_QUORUM: Item<u32> = Item::new("removal_quorum");
 
-pub const WHITELISTED_ACTIONS: Map<Vec<u8>, bool> = Map::new("whitelisted_actions");
+// raw actions whitelisted by earlier versions, re-keyed into WHITELISTED_ACTIONS on migration
+pub const LEGACY_WHITELISTED_ACTIONS: Map<Vec<u8>, bool> = Map::new("whitelisted_actions");
+// keyed by the sha256 hash of the rlp encoded action
+pub const WHITELISTED_ACTIONS: Map<&[u8], WhitelistedAction> =
+    Map::new("whitelisted_action_hashes");
+
+// whitelist approvals collected until the proposer threshold is reached
+pub const ACTION_APPROVALS: Map<&[u8], PendingAction> = Map::new("action_approvals");
+
+pub const TIMELOCK: Item<u64> = Item::new("timelock");
+pub const NEXT_ACTION_ID: Item<u64> = Item::new("next_action_id");
+pub const QUEUED_ACTIONS: Map<u64, QueuedAction> = Map::new("queued_actions");
+
+#[cw_serde]
+pub struct WhitelistedAction {
+    pub proposer: Addr,
+    pub expiry: Expiration,
+}
+
+#[cw_serde]
+pub struct PendingAction {
+    pub approvals: Vec<Addr>,
+    pub expiry: Expiration,
+}
+
+#[cw_serde]
+pub struct QueuedAction {
+    pub data: Vec<u8>,
+    pub eta: Timestamp,
+}
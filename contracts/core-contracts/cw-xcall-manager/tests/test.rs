@@ -2,28 +2,44 @@ use cosmwasm_std::testing::{
     mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
 };
 use cosmwasm_std::{
-    from_binary, to_binary, Addr, Binary, ContractInfoResponse, ContractResult, CosmosMsg, Deps,
-    Empty, OwnedDeps, SystemResult, WasmMsg, WasmQuery,
+    coin, from_binary, to_binary, Addr, BankMsg, Binary, ContractInfoResponse, ContractResult,
+    CosmosMsg, Deps, Empty, Order, OwnedDeps, Response, SystemResult, WasmMsg, WasmQuery,
 };
 use cw_common::asset_manager_msg::{
     ExecuteMsg as AssetManagerExecuteMessage, MigrateMsg as AssetManageMigrateMsg,
 };
+use cw_common::events::GOVERNANCE_ACTION_EXECUTED;
+use cw_common::messages::HubMessage;
+use cw_common::network_address::{FromStr, NetId};
 use cw_common::xcall_manager_msg::{
-    ConfigureProtocols, Execute, ExecuteMsg, InstantiateMsg, Migrate, ProtocolConfig, QueryMsg,
-    UpdateAdmin,
+    BankSend, Batch, ConfigResponse, ConfigureProtocols, ConfigureRemovalQuorum, ConfigureRoles,
+    ConfigureTimelock, Execute, ExecuteMsg, GovernanceAction, InstantiateMsg, Migrate, MigrateMsg,
+    PendingActionResponse, ProposerResponse, ProtocolConfig, QueryMsg, QueuedActionResponse,
+    UpdateAdmin, WhitelistedActionResponse,
 };
-use cw_ibc_rlp_lib::rlp::encode;
-use cw_xcall_manager::contract::{execute, instantiate, query};
+use cw_ibc_rlp_lib::rlp::{encode, RlpStream};
+use cw_utils::Expiration;
+use cw_xcall_manager::contract::{action_hash, execute, instantiate, migrate, query};
+use cw_xcall_manager::state::{LEGACY_WHITELISTED_ACTIONS, PROPOSER as LEGACY_PROPOSER};
 use cw_xcall_manager::ContractError;
 
 pub const XCALL_NETWORK_ADDRESS: &str = "archway/xcall";
 pub const XCALL_ADDR: &str = "xcall";
 pub const GOVERNANCE: &str = "icon/governance";
 pub const PROPOSER: &str = "proposer";
+pub const ICON_CONNECTION: &str = "cx0000000000000000000000000000000000000001";
 
 fn setup(
+    deps: OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    protocols: Vec<String>,
+) -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
+    setup_with_timelock(deps, protocols, None)
+}
+
+fn setup_with_timelock(
     mut deps: OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
     protocols: Vec<String>,
+    timelock: Option<u64>,
 ) -> OwnedDeps<MockStorage, MockApi, MockQuerier, Empty> {
     deps.querier.update_wasm(|r: &WasmQuery| match r {
         WasmQuery::Smart {
@@ -45,6 +61,7 @@ fn setup(
             sources: protocols.clone(),
             destinations: protocols,
         },
+        timelock,
     };
 
     let info = mock_info(PROPOSER, &[]);
@@ -54,6 +71,35 @@ fn setup(
     deps
 }
 
+// whitelists the action and delivers it from governance
+fn govern(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockQuerier, Empty>,
+    protocols: &[String],
+    action: Vec<u8>,
+) -> Response {
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::WhitelistAction {
+            action: action.clone(),
+            expiry: None,
+        },
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(XCALL_ADDR, &[]),
+        ExecuteMsg::HandleCallMessage {
+            from: GOVERNANCE.to_string(),
+            data: action,
+            protocols: Some(protocols.to_vec()),
+        },
+    )
+    .unwrap()
+}
+
 #[test]
 fn verify_protocol_empty() {
     // Arrange
@@ -75,6 +121,62 @@ fn verify_protocol_base() {
     verify_protocol(deps.as_ref(), protocols, true);
 }
 
+#[test]
+fn verify_protocol_unordered() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string(), "Protocol2".to_string()];
+    deps = setup(deps, protocols);
+
+    // Act
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::VerifyProtocols {
+            protocols: vec!["Protocol2".to_string(), "Protocol1".to_string()],
+        },
+    );
+
+    // Assert
+    assert_eq!(res.unwrap(), to_binary(&true).unwrap());
+}
+
+#[test]
+fn query_config() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    deps = setup_with_timelock(deps, vec![], Some(100));
+
+    // Act
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::GetConfig {});
+
+    // Assert
+    let config: ConfigResponse = from_binary(&res.unwrap()).unwrap();
+    assert_eq!(
+        config,
+        ConfigResponse {
+            xcall: XCALL_ADDR.to_string(),
+            icon_governance: GOVERNANCE.to_string(),
+            timelock: Some(100),
+        }
+    );
+
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::GetProposer {});
+    let proposer: ProposerResponse = from_binary(&res.unwrap()).unwrap();
+    assert_eq!(proposer.proposers, vec![Addr::unchecked(PROPOSER)]);
+    assert_eq!(proposer.threshold, 1);
+    assert!(proposer.guardians.is_empty());
+
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::GetProposedRemoval {});
+    assert_eq!(res.unwrap(), to_binary(&Vec::<String>::new()).unwrap());
+    let msg = ExecuteMsg::ProposeChange {
+        protocol: "Protocol1".to_string(),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(PROPOSER, &[]), msg).unwrap();
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::GetProposedRemoval {});
+    assert_eq!(res.unwrap(), to_binary(&vec!["Protocol1"]).unwrap());
+}
+
 #[test]
 fn verify_protocol_invalid() {
     // Arrange
@@ -117,6 +219,41 @@ fn verify_only_xcall() {
     assert!(res.is_err() && res.unwrap_err() == ContractError::OnlyXCall);
 }
 
+#[test]
+fn malformed_call_message() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+    let mut stream = RlpStream::new_list(1);
+    stream.append(&"ConfigureProtocols".to_string());
+    let configure_protocols = stream.out().to_vec();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::WhitelistAction {
+            action: configure_protocols.clone(),
+            expiry: None,
+        },
+    )
+    .unwrap();
+
+    // Act & Assert
+    for data in [vec![0x01], configure_protocols] {
+        let msg = ExecuteMsg::HandleCallMessage {
+            from: GOVERNANCE.to_string(),
+            data,
+            protocols: Some(protocols.clone()),
+        };
+        let res = execute(deps.as_mut(), mock_env(), mock_info(XCALL_ADDR, &[]), msg);
+        assert!(matches!(
+            res.unwrap_err(),
+            ContractError::DecoderError { .. }
+        ));
+    }
+}
+
 #[test]
 fn verify_only_governance() {
     // Arrange
@@ -143,16 +280,18 @@ fn configure_protocol() {
     let mut deps = mock_dependencies();
     let protocols = vec!["Protocol1".to_string()];
     let new_sources = vec!["Protocol2".to_string()];
-    let new_destination = vec!["icon/dst".to_string()];
+    let new_destination = vec![ICON_CONNECTION.to_string()];
     deps = setup(deps, protocols.clone());
 
     let xcall_message = ConfigureProtocols {
         sources: new_sources.clone(),
         destinations: new_destination.clone(),
+        nid: None,
     };
     let encoded_message = encode(&xcall_message).to_vec();
     let whitelist_msg = ExecuteMsg::WhitelistAction {
         action: encoded_message.clone(),
+        expiry: None,
     };
 
     let msg = ExecuteMsg::HandleCallMessage {
@@ -175,12 +314,147 @@ fn configure_protocol() {
 
     verify_protocol(deps.as_ref(), protocols, false);
     verify_protocol(deps.as_ref(), new_sources.clone(), true);
-    let res = query(deps.as_ref(), mock_env(), QueryMsg::GetProtocols {});
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::GetProtocols {
+            nid: NetId::from_str("icon").unwrap(),
+        },
+    );
     let current_protocols: ProtocolConfig = from_binary(&res.unwrap()).unwrap();
     assert!(current_protocols.sources == new_sources);
     assert!(current_protocols.destinations == new_destination);
 }
 
+#[test]
+fn configure_route_protocols() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+
+    let xcall_message = ConfigureProtocols {
+        sources: vec!["Protocol2".to_string()],
+        destinations: vec!["0x52908400098527886E0F7030069857D2E4169EE7".to_string()],
+        nid: Some("0x1.eth".to_string()),
+    };
+    let encoded_message = encode(&xcall_message).to_vec();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::WhitelistAction {
+            action: encoded_message.clone(),
+            expiry: None,
+        },
+    )
+    .unwrap();
+    let msg = ExecuteMsg::HandleCallMessage {
+        from: GOVERNANCE.to_string(),
+        data: encoded_message,
+        protocols: Some(protocols.clone()),
+    };
+
+    // Act
+    let res = execute(deps.as_mut(), mock_env(), mock_info(XCALL_ADDR, &[]), msg);
+
+    // Assert
+    assert!(res.is_ok());
+    let route_protocols = |nid: &str| -> ProtocolConfig {
+        let msg = QueryMsg::GetProtocols {
+            nid: NetId::from_str(nid).unwrap(),
+        };
+        from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+    };
+    assert_eq!(
+        route_protocols("0x1.eth"),
+        ProtocolConfig {
+            sources: vec!["Protocol2".to_string()],
+            destinations: vec!["0x52908400098527886E0F7030069857D2E4169EE7".to_string()],
+        }
+    );
+    // governance messages keep using the governance route
+    verify_protocol(deps.as_ref(), protocols.clone(), true);
+    // unconfigured routes fall back to the governance route
+    assert_eq!(route_protocols("0x2.bsc").sources, protocols);
+}
+
+#[test]
+fn configure_protocols_validation() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+
+    let cases = [
+        (
+            ConfigureProtocols {
+                sources: vec![],
+                destinations: vec![ICON_CONNECTION.to_string()],
+                nid: None,
+            },
+            ContractError::EmptySources,
+        ),
+        (
+            ConfigureProtocols {
+                sources: vec!["Protocol2".to_string(), "Protocol2".to_string()],
+                destinations: vec![ICON_CONNECTION.to_string()],
+                nid: None,
+            },
+            ContractError::DuplicateProtocol {
+                protocol: "Protocol2".to_string(),
+            },
+        ),
+        (
+            ConfigureProtocols {
+                sources: vec!["Protocol2".to_string()],
+                destinations: vec!["icon/dst".to_string()],
+                nid: None,
+            },
+            ContractError::InvalidDestination {
+                nid: "icon".to_string(),
+                destination: "icon/dst".to_string(),
+            },
+        ),
+        (
+            ConfigureProtocols {
+                sources: vec!["Protocol2".to_string()],
+                destinations: vec![ICON_CONNECTION.to_string()],
+                nid: Some("0x1.eth".to_string()),
+            },
+            ContractError::InvalidDestination {
+                nid: "0x1.eth".to_string(),
+                destination: ICON_CONNECTION.to_string(),
+            },
+        ),
+    ];
+
+    for (xcall_message, expected) in cases {
+        let encoded_message = encode(&xcall_message).to_vec();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(PROPOSER, &[]),
+            ExecuteMsg::WhitelistAction {
+                action: encoded_message.clone(),
+                expiry: None,
+            },
+        )
+        .unwrap();
+        let msg = ExecuteMsg::HandleCallMessage {
+            from: GOVERNANCE.to_string(),
+            data: encoded_message,
+            protocols: Some(protocols.clone()),
+        };
+
+        // Act
+        let res = execute(deps.as_mut(), mock_env(), mock_info(XCALL_ADDR, &[]), msg);
+
+        // Assert
+        assert_eq!(res.unwrap_err(), expected);
+    }
+}
+
 #[test]
 fn execute_message() {
     // Arrange
@@ -196,6 +470,7 @@ fn execute_message() {
     let xcall_message = Execute {
         contract_addr: to.clone(),
         message: Binary::to_base64(&to_binary(&execute_message).unwrap()),
+        funds: None,
     };
     let expected_message = CosmosMsg::Wasm(WasmMsg::Execute {
         contract_addr: to,
@@ -205,6 +480,7 @@ fn execute_message() {
     let encoded_message = encode(&xcall_message).to_vec();
     let whitelist_msg = ExecuteMsg::WhitelistAction {
         action: encoded_message.clone(),
+        expiry: None,
     };
 
     let msg = ExecuteMsg::HandleCallMessage {
@@ -251,6 +527,7 @@ fn migrate_message() {
     let encoded_message = encode(&xcall_message).to_vec();
     let whitelist_msg = ExecuteMsg::WhitelistAction {
         action: encoded_message.clone(),
+        expiry: None,
     };
 
     let msg = ExecuteMsg::HandleCallMessage {
@@ -294,6 +571,7 @@ fn update_admin() {
     let encoded_message = encode(&xcall_message).to_vec();
     let whitelist_msg = ExecuteMsg::WhitelistAction {
         action: encoded_message.clone(),
+        expiry: None,
     };
 
     let msg = ExecuteMsg::HandleCallMessage {
@@ -334,7 +612,7 @@ fn only_proposer() {
     assert!(res.is_err() && res.unwrap_err() == ContractError::OnlyProposer);
 
     // Act & Assert
-    let msg = ExecuteMsg::RemoveProposal {};
+    let msg = ExecuteMsg::RemoveProposal { protocol: None };
     let res = execute(
         deps.as_mut(),
         mock_env(),
@@ -372,11 +650,13 @@ fn propose_and_enact_removal() {
 
     let xcall_message = ConfigureProtocols {
         sources: new_protocols.clone(),
-        destinations: new_protocols.clone(),
+        destinations: vec![ICON_CONNECTION.to_string()],
+        nid: None,
     };
     let encoded_message = encode(&xcall_message).to_vec();
     let whitelist_msg = ExecuteMsg::WhitelistAction {
         action: encoded_message.clone(),
+        expiry: None,
     };
 
     let msg = ExecuteMsg::HandleCallMessage {
@@ -401,6 +681,127 @@ fn propose_and_enact_removal() {
     verify_protocol(deps.as_ref(), new_protocols, true)
 }
 
+#[test]
+fn remove_multiple_protocols_with_quorum() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols: Vec<String> = (1..=5).map(|i| format!("Protocol{i}")).collect();
+    let new_protocols = protocols[2..].to_vec();
+    deps = setup(deps, protocols.clone());
+
+    let configure_protocols = encode(&ConfigureProtocols {
+        sources: new_protocols.clone(),
+        destinations: vec![ICON_CONNECTION.to_string()],
+        nid: None,
+    })
+    .to_vec();
+    let handle_msg = |data: Vec<u8>, protocols: Vec<String>| ExecuteMsg::HandleCallMessage {
+        from: GOVERNANCE.to_string(),
+        data,
+        protocols: Some(protocols),
+    };
+    let whitelist_msg = |action: Vec<u8>| ExecuteMsg::WhitelistAction {
+        action,
+        expiry: None,
+    };
+    for protocol in &protocols[..2] {
+        let msg = ExecuteMsg::ProposeChange {
+            protocol: protocol.clone(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(PROPOSER, &[]), msg).unwrap();
+    }
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        whitelist_msg(configure_protocols.clone()),
+    )
+    .unwrap();
+
+    // Act & Assert
+    // without a configured quorum only one source may be missing
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(XCALL_ADDR, &[]),
+        handle_msg(configure_protocols.clone(), new_protocols.clone()),
+    );
+    assert_eq!(res.unwrap_err(), ContractError::InvalidProtocol);
+
+    let configure_quorum = encode(&ConfigureRemovalQuorum { quorum: 3 }).to_vec();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        whitelist_msg(configure_quorum.clone()),
+    )
+    .unwrap();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(XCALL_ADDR, &[]),
+        handle_msg(configure_quorum, protocols.clone()),
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(XCALL_ADDR, &[]),
+        handle_msg(configure_protocols, new_protocols.clone()),
+    );
+    assert!(res.is_ok());
+    verify_protocol(deps.as_ref(), new_protocols, true);
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::GetProposedRemoval {});
+    assert_eq!(res.unwrap(), to_binary(&Vec::<String>::new()).unwrap());
+}
+
+#[test]
+fn removal_quorum_clamped_to_sources() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols: Vec<String> = (1..=5).map(|i| format!("Protocol{i}")).collect();
+    deps = setup(deps, protocols.clone());
+    govern(
+        &mut deps,
+        &protocols,
+        encode(&ConfigureRemovalQuorum { quorum: 4 }).to_vec(),
+    );
+    let quorum = |deps: Deps<'_, Empty>| -> Option<u32> {
+        from_binary(&query(deps, mock_env(), QueryMsg::GetRemovalQuorum {}).unwrap()).unwrap()
+    };
+
+    // Act
+    let res = govern(
+        &mut deps,
+        &protocols,
+        encode(&ConfigureProtocols {
+            sources: protocols[..2].to_vec(),
+            destinations: vec![ICON_CONNECTION.to_string()],
+            nid: None,
+        })
+        .to_vec(),
+    );
+
+    // Assert
+    assert_eq!(res.events[1].ty, "RemovalQuorumConfigured");
+    assert_eq!(res.events[1].attributes[0].value, "2");
+    assert_eq!(quorum(deps.as_ref()), Some(2));
+
+    // a quorum that still fits is kept
+    govern(
+        &mut deps,
+        &protocols[..2],
+        encode(&ConfigureProtocols {
+            sources: protocols[..3].to_vec(),
+            destinations: vec![ICON_CONNECTION.to_string()],
+            nid: None,
+        })
+        .to_vec(),
+    );
+    assert_eq!(quorum(deps.as_ref()), Some(2));
+}
+
 #[test]
 fn invalid_proposal() {
     // Arrange
@@ -417,7 +818,8 @@ fn invalid_proposal() {
 
     let xcall_message = ConfigureProtocols {
         sources: new_protocols.clone(),
-        destinations: new_protocols.clone(),
+        destinations: vec![ICON_CONNECTION.to_string()],
+        nid: None,
     };
     let encoded_message = encode(&xcall_message).to_vec();
     let msg = ExecuteMsg::HandleCallMessage {
@@ -461,21 +863,882 @@ fn change_proposer() {
     assert!(res.is_ok());
 }
 
-fn verify_protocol(deps: Deps<'_, Empty>, protocols: Vec<String>, valid: bool) {
+#[test]
+fn change_proposer_to_existing_proposer() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+    govern(
+        &mut deps,
+        &protocols,
+        encode(&ConfigureRoles {
+            proposers: vec![PROPOSER.to_string(), "proposer2".to_string()],
+            threshold: 2,
+            guardians: vec![],
+        })
+        .to_vec(),
+    );
+
+    // Act
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::ChangeProposer {
+            proposer: Addr::unchecked("proposer2"),
+        },
+    );
+
+    // Assert
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::AlreadyProposer {
+            proposer: Addr::unchecked("proposer2")
+        }
+    );
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::ChangeProposer {
+            proposer: Addr::unchecked("Proposer3"),
+        },
+    );
+    assert!(res.is_err());
+    let res = query(deps.as_ref(), mock_env(), QueryMsg::GetProposer {});
+    let res: ProposerResponse = from_binary(&res.unwrap()).unwrap();
+    assert_eq!(
+        res.proposers,
+        vec![Addr::unchecked(PROPOSER), Addr::unchecked("proposer2")]
+    );
+    assert_eq!(res.threshold, 2);
+}
+
+#[test]
+fn timelocked_execute_message() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup_with_timelock(deps, protocols.clone(), Some(100));
+
+    let xcall_message = Execute {
+        contract_addr: "contract".to_string(),
+        message: Binary::to_base64(&to_binary(&"message").unwrap()),
+        funds: None,
+    };
+    let encoded_message = encode(&xcall_message).to_vec();
+    let whitelist_msg = ExecuteMsg::WhitelistAction {
+        action: encoded_message.clone(),
+        expiry: None,
+    };
+    let msg = ExecuteMsg::HandleCallMessage {
+        from: GOVERNANCE.to_string(),
+        data: encoded_message,
+        protocols: Some(protocols),
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        whitelist_msg,
+    )
+    .unwrap();
+
+    // Act
+    let res = execute(deps.as_mut(), mock_env(), mock_info(XCALL_ADDR, &[]), msg).unwrap();
+
+    // Assert
+    assert!(res.messages.is_empty());
+    assert_eq!(res.events[0].ty, "ActionQueued");
     let res = query(
-        deps,
+        deps.as_ref(),
         mock_env(),
-        QueryMsg::VerifyProtocols {
-            protocols: protocols.clone(),
+        QueryMsg::GetQueuedActions {
+            start_after: None,
+            limit: None,
         },
     );
-    assert!(res.is_ok());
-    assert!(res.unwrap() == to_binary(&valid).unwrap());
-    if !valid {
-        return;
-    }
+    let queued: Vec<QueuedActionResponse> = from_binary(&res.unwrap()).unwrap();
+    assert_eq!(queued.len(), 1);
+    assert_eq!(queued[0].id, 0);
+    assert_eq!(queued[0].eta, mock_env().block.time.plus_seconds(100));
+    assert_eq!(
+        queued[0].action,
+        HubMessage::Governance(GovernanceAction::Execute(xcall_message))
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteQueued { id: 0 },
+    );
+    assert!(matches!(
+        res.unwrap_err(),
+        ContractError::QueuedActionNotReady { id: 0, .. }
+    ));
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteQueued { id: 0 },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    let executed = &res.events[0];
+    assert_eq!(executed.ty, GOVERNANCE_ACTION_EXECUTED);
+    assert_eq!(executed.attributes[0].value, "Execute");
+    assert_eq!(executed.attributes[1].value, "0");
+
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteQueued { id: 0 },
+    );
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::QueuedActionNotFound { id: 0 }
+    );
+}
+
+#[test]
+fn veto_queued_action() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup_with_timelock(deps, protocols.clone(), Some(100));
+
+    let xcall_message = UpdateAdmin {
+        contract_addr: "contract".to_string(),
+        admin: "admin".to_string(),
+    };
+    let encoded_message = encode(&xcall_message).to_vec();
+    let whitelist_msg = ExecuteMsg::WhitelistAction {
+        action: encoded_message.clone(),
+        expiry: None,
+    };
+    let msg = ExecuteMsg::HandleCallMessage {
+        from: GOVERNANCE.to_string(),
+        data: encoded_message,
+        protocols: Some(protocols),
+    };
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        whitelist_msg,
+    )
+    .unwrap();
+    execute(deps.as_mut(), mock_env(), mock_info(XCALL_ADDR, &[]), msg).unwrap();
+
+    // Act
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("not_proposer", &[]),
+        ExecuteMsg::VetoQueued { id: 0 },
+    );
+    assert_eq!(res.unwrap_err(), ContractError::OnlyProposer);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::VetoQueued { id: 0 },
+    );
 
-    let res = query(deps, mock_env(), QueryMsg::GetProtocols {});
+    // Assert
+    assert!(res.is_ok());
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteQueued { id: 0 },
+    );
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::QueuedActionNotFound { id: 0 }
+    );
+}
+
+#[test]
+fn guardian_vetoes_queued_action() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+    govern(
+        &mut deps,
+        &protocols,
+        encode(&ConfigureRoles {
+            proposers: vec![PROPOSER.to_string()],
+            threshold: 1,
+            guardians: vec!["guardian".to_string()],
+        })
+        .to_vec(),
+    );
+    govern(
+        &mut deps,
+        &protocols,
+        encode(&ConfigureTimelock { timelock: 100 }).to_vec(),
+    );
+    govern(
+        &mut deps,
+        &protocols,
+        encode(&UpdateAdmin {
+            contract_addr: "contract".to_string(),
+            admin: "admin".to_string(),
+        })
+        .to_vec(),
+    );
+
+    // Act
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::VetoQueued { id: 0 },
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(res.events[0].ty, "ActionVetoed");
+    assert_eq!(res.events[0].attributes[1].value, "guardian");
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::VetoQueued { id: 0 },
+    );
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::QueuedActionNotFound { id: 0 }
+    );
+}
+
+#[test]
+fn configure_timelock() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+    let timelock = |deps: Deps<'_, Empty>| -> Option<u64> {
+        from_binary(&query(deps, mock_env(), QueryMsg::GetTimelock {}).unwrap()).unwrap()
+    };
+    let update_admin = encode(&UpdateAdmin {
+        contract_addr: "contract".to_string(),
+        admin: "admin".to_string(),
+    })
+    .to_vec();
+
+    // Act
+    let res = govern(
+        &mut deps,
+        &protocols,
+        encode(&ConfigureTimelock { timelock: 3600 }).to_vec(),
+    );
+
+    // Assert
+    assert_eq!(res.events[0].ty, "TimelockConfigured");
+    assert_eq!(res.events[0].attributes[0].value, "3600");
+    assert_eq!(timelock(deps.as_ref()), Some(3600));
+    let res = govern(&mut deps, &protocols, update_admin.clone());
+    assert!(res.messages.is_empty());
+    assert_eq!(res.events[0].ty, "ActionQueued");
+
+    // lowering the timelock is itself subject to the current timelock
+    let res = govern(
+        &mut deps,
+        &protocols,
+        encode(&ConfigureTimelock { timelock: 0 }).to_vec(),
+    );
+    assert_eq!(res.events[0].ty, "ActionQueued");
+    assert_eq!(timelock(deps.as_ref()), Some(3600));
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(3600);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteQueued { id: 1 },
+    )
+    .unwrap();
+    assert_eq!(res.events[0].ty, "TimelockConfigured");
+    assert_eq!(res.events[1].ty, GOVERNANCE_ACTION_EXECUTED);
+
+    // a zero timelock executes actions on delivery again
+    assert_eq!(timelock(deps.as_ref()), None);
+    let res = govern(&mut deps, &protocols, update_admin);
+    assert_eq!(res.messages.len(), 1);
+}
+
+#[test]
+fn timelocked_configuration() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string(), "Protocol2".to_string()];
+    deps = setup_with_timelock(deps, protocols.clone(), Some(100));
+    let quorum = |deps: Deps<'_, Empty>| -> Option<u32> {
+        from_binary(&query(deps, mock_env(), QueryMsg::GetRemovalQuorum {}).unwrap()).unwrap()
+    };
+
+    // Act
+    let res = govern(
+        &mut deps,
+        &protocols,
+        encode(&ConfigureRemovalQuorum { quorum: 1 }).to_vec(),
+    );
+
+    // Assert
+    assert_eq!(res.events[0].ty, "ActionQueued");
+    assert_eq!(quorum(deps.as_ref()), None);
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::GetQueuedActions {
+            start_after: None,
+            limit: None,
+        },
+    );
+    let queued: Vec<QueuedActionResponse> = from_binary(&res.unwrap()).unwrap();
+    assert_eq!(
+        queued[0].action,
+        HubMessage::ConfigureRemovalQuorum(ConfigureRemovalQuorum { quorum: 1 })
+    );
+
+    let mut env = mock_env();
+    env.block.time = env.block.time.plus_seconds(100);
+    let res = execute(
+        deps.as_mut(),
+        env,
+        mock_info("anyone", &[]),
+        ExecuteMsg::ExecuteQueued { id: 0 },
+    )
+    .unwrap();
+    assert_eq!(res.events[0].ty, "RemovalQuorumConfigured");
+    let executed = &res.events[1];
+    assert_eq!(executed.ty, GOVERNANCE_ACTION_EXECUTED);
+    assert_eq!(executed.attributes[0].value, "ConfigureRemovalQuorum");
+    assert_eq!(executed.attributes[1].value, "0");
+    assert_eq!(quorum(deps.as_ref()), Some(1));
+}
+
+#[test]
+fn batch_message() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+
+    let execute_message = AssetManagerExecuteMessage::ConfigureNative {
+        native_token_address: "a".to_string(),
+        native_token_manager: "b".to_string(),
+    };
+    let xcall_message = Batch {
+        actions: vec![
+            GovernanceAction::Execute(Execute {
+                contract_addr: "asset_manager".to_string(),
+                message: Binary::to_base64(&to_binary(&execute_message).unwrap()),
+                funds: None,
+            }),
+            GovernanceAction::UpdateAdmin(UpdateAdmin {
+                contract_addr: "hub".to_string(),
+                admin: "admin".to_string(),
+            }),
+        ],
+    };
+    let expected_messages = vec![
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "asset_manager".to_string(),
+            msg: to_binary(&execute_message).unwrap(),
+            funds: vec![],
+        }),
+        CosmosMsg::Wasm(WasmMsg::UpdateAdmin {
+            contract_addr: "hub".to_string(),
+            admin: "admin".to_string(),
+        }),
+    ];
+    let encoded_message = encode(&xcall_message).to_vec();
+    let whitelist_msg = ExecuteMsg::WhitelistAction {
+        action: encoded_message.clone(),
+        expiry: None,
+    };
+    let msg = ExecuteMsg::HandleCallMessage {
+        from: GOVERNANCE.to_string(),
+        data: encoded_message,
+        protocols: Some(protocols),
+    };
+
+    // Act
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        whitelist_msg,
+    )
+    .unwrap();
+    let res = execute(deps.as_mut(), mock_env(), mock_info(XCALL_ADDR, &[]), msg).unwrap();
+
+    // Assert
+    let messages: Vec<CosmosMsg> = res.messages.into_iter().map(|m| m.msg).collect();
+    assert_eq!(messages, expected_messages);
+}
+
+#[test]
+fn funded_actions() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+
+    let xcall_message = Batch {
+        actions: vec![
+            GovernanceAction::Execute(Execute {
+                contract_addr: "hub".to_string(),
+                message: Binary::to_base64(&to_binary(&"message").unwrap()),
+                funds: Some(vec![coin(100, "uarch")]),
+            }),
+            GovernanceAction::BankSend(BankSend {
+                to_address: "fee_collector".to_string(),
+                amount: vec![coin(50, "uarch")],
+            }),
+        ],
+    };
+    let expected_messages = vec![
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: "hub".to_string(),
+            msg: to_binary(&"message").unwrap(),
+            funds: vec![coin(100, "uarch")],
+        }),
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: "fee_collector".to_string(),
+            amount: vec![coin(50, "uarch")],
+        }),
+    ];
+    let encoded_message = encode(&xcall_message).to_vec();
+    let whitelist_msg = ExecuteMsg::WhitelistAction {
+        action: encoded_message.clone(),
+        expiry: None,
+    };
+    let msg = ExecuteMsg::HandleCallMessage {
+        from: GOVERNANCE.to_string(),
+        data: encoded_message,
+        protocols: Some(protocols),
+    };
+
+    // Act
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        whitelist_msg,
+    )
+    .unwrap();
+    let res = execute(deps.as_mut(), mock_env(), mock_info(XCALL_ADDR, &[]), msg).unwrap();
+
+    // Assert
+    let messages: Vec<CosmosMsg> = res.messages.into_iter().map(|m| m.msg).collect();
+    assert_eq!(messages, expected_messages);
+}
+
+#[test]
+fn whitelist_expiry() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+
+    let xcall_message = UpdateAdmin {
+        contract_addr: "contract".to_string(),
+        admin: "admin".to_string(),
+    };
+    let encoded_message = encode(&xcall_message).to_vec();
+    let expiry = Expiration::AtHeight(mock_env().block.height + 10);
+
+    // Act
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::WhitelistAction {
+            action: encoded_message.clone(),
+            expiry: Some(expiry),
+        },
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(res.events[0].ty, "ActionWhitelisted");
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::GetWhitelistedActions {
+            start_after: None,
+            limit: None,
+        },
+    );
+    let whitelisted: Vec<WhitelistedActionResponse> = from_binary(&res.unwrap()).unwrap();
+    assert_eq!(
+        whitelisted,
+        vec![WhitelistedActionResponse {
+            hash: action_hash(&encoded_message).into(),
+            proposer: Addr::unchecked(PROPOSER),
+            expiry,
+        }]
+    );
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::IsActionWhitelisted {
+            action: encoded_message.clone(),
+        },
+    );
+    assert_eq!(res.unwrap(), to_binary(&true).unwrap());
+
+    let mut env = mock_env();
+    env.block.height += 10;
+    let res = query(
+        deps.as_ref(),
+        env.clone(),
+        QueryMsg::IsActionWhitelisted {
+            action: encoded_message.clone(),
+        },
+    );
+    assert_eq!(res.unwrap(), to_binary(&false).unwrap());
+
+    let msg = ExecuteMsg::HandleCallMessage {
+        from: GOVERNANCE.to_string(),
+        data: encoded_message,
+        protocols: Some(protocols),
+    };
+    let res = execute(deps.as_mut(), env, mock_info(XCALL_ADDR, &[]), msg);
+    assert_eq!(res.unwrap_err(), ContractError::ActionExpired);
+}
+
+#[test]
+fn migrate_legacy_whitelist() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+
+    let action = encode(&UpdateAdmin {
+        contract_addr: "contract".to_string(),
+        admin: "admin".to_string(),
+    })
+    .to_vec();
+    let removed = encode(&UpdateAdmin {
+        contract_addr: "contract".to_string(),
+        admin: "other".to_string(),
+    })
+    .to_vec();
+    LEGACY_PROPOSER
+        .save(deps.as_mut().storage, &Addr::unchecked("legacy"))
+        .unwrap();
+    LEGACY_WHITELISTED_ACTIONS
+        .save(deps.as_mut().storage, action.clone(), &true)
+        .unwrap();
+    LEGACY_WHITELISTED_ACTIONS
+        .save(deps.as_mut().storage, removed.clone(), &false)
+        .unwrap();
+
+    // Act
+    let res = migrate(deps.as_mut(), mock_env(), MigrateMsg { protocols: None }).unwrap();
+
+    // Assert
+    assert_eq!(res.attributes[1].value, "1");
+    assert!(LEGACY_WHITELISTED_ACTIONS
+        .keys(deps.as_ref().storage, None, None, Order::Ascending)
+        .next()
+        .is_none());
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::GetWhitelistedActions {
+            start_after: None,
+            limit: None,
+        },
+    );
+    let whitelisted: Vec<WhitelistedActionResponse> = from_binary(&res.unwrap()).unwrap();
+    assert_eq!(
+        whitelisted,
+        vec![WhitelistedActionResponse {
+            hash: action_hash(&action).into(),
+            proposer: Addr::unchecked("legacy"),
+            expiry: Expiration::AtTime(mock_env().block.time.plus_seconds(7 * 24 * 60 * 60)),
+        }]
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(XCALL_ADDR, &[]),
+        ExecuteMsg::HandleCallMessage {
+            from: GOVERNANCE.to_string(),
+            data: action,
+            protocols: Some(protocols.clone()),
+        },
+    );
+    assert_eq!(res.unwrap().messages.len(), 1);
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(XCALL_ADDR, &[]),
+        ExecuteMsg::HandleCallMessage {
+            from: GOVERNANCE.to_string(),
+            data: removed,
+            protocols: Some(protocols),
+        },
+    );
+    assert_eq!(res.unwrap_err(), ContractError::ActionNotWhitelisted);
+}
+
+#[test]
+fn proposer_threshold_and_guardian() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+
+    let roles = ConfigureRoles {
+        proposers: vec![PROPOSER.to_string(), "proposer2".to_string()],
+        threshold: 2,
+        guardians: vec!["guardian".to_string()],
+    };
+    let encoded_message = encode(&roles).to_vec();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::WhitelistAction {
+            action: encoded_message.clone(),
+            expiry: None,
+        },
+    )
+    .unwrap();
+    let msg = ExecuteMsg::HandleCallMessage {
+        from: GOVERNANCE.to_string(),
+        data: encoded_message,
+        protocols: Some(protocols),
+    };
+    execute(deps.as_mut(), mock_env(), mock_info(XCALL_ADDR, &[]), msg).unwrap();
+
+    // Act & Assert
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        ExecuteMsg::ProposeChange {
+            protocol: "Protocol1".to_string(),
+        },
+    );
+    assert!(res.is_ok());
+
+    let action = encode(&UpdateAdmin {
+        contract_addr: "contract".to_string(),
+        admin: "admin".to_string(),
+    })
+    .to_vec();
+    let whitelist_msg = ExecuteMsg::WhitelistAction {
+        action: action.clone(),
+        expiry: None,
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("guardian", &[]),
+        whitelist_msg.clone(),
+    );
+    assert_eq!(res.unwrap_err(), ContractError::OnlyProposer);
+
+    let is_whitelisted = |deps: Deps<'_, Empty>| -> bool {
+        let res = query(
+            deps,
+            mock_env(),
+            QueryMsg::IsActionWhitelisted {
+                action: action.clone(),
+            },
+        );
+        from_binary(&res.unwrap()).unwrap()
+    };
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        whitelist_msg.clone(),
+    )
+    .unwrap();
+    assert_eq!(res.events[0].ty, "ActionApproved");
+    assert!(!is_whitelisted(deps.as_ref()));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer2", &[]),
+        whitelist_msg,
+    )
+    .unwrap();
+    assert_eq!(res.events[0].ty, "ActionWhitelisted");
+    assert!(is_whitelisted(deps.as_ref()));
+}
+
+#[test]
+fn approval_expiry_mismatch() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+    govern(
+        &mut deps,
+        &protocols,
+        encode(&ConfigureRoles {
+            proposers: vec![PROPOSER.to_string(), "proposer2".to_string()],
+            threshold: 2,
+            guardians: vec![],
+        })
+        .to_vec(),
+    );
+    let action = encode(&UpdateAdmin {
+        contract_addr: "contract".to_string(),
+        admin: "admin".to_string(),
+    })
+    .to_vec();
+    let expiry = Expiration::AtHeight(mock_env().block.height + 10);
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::WhitelistAction {
+            action: action.clone(),
+            expiry: Some(expiry),
+        },
+    )
+    .unwrap();
+
+    // Act
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer2", &[]),
+        ExecuteMsg::WhitelistAction {
+            action: action.clone(),
+            expiry: None,
+        },
+    );
+
+    // Assert
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::ActionParametersMismatch {
+            expiry: Expiration::Never {},
+            proposed: expiry,
+        }
+    );
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::GetPendingAction {
+            action: action.clone(),
+        },
+    );
+    let pending: Option<PendingActionResponse> = from_binary(&res.unwrap()).unwrap();
+    assert_eq!(
+        pending,
+        Some(PendingActionResponse {
+            approvals: vec![Addr::unchecked(PROPOSER)],
+            expiry,
+        })
+    );
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("proposer2", &[]),
+        ExecuteMsg::WhitelistAction {
+            action: action.clone(),
+            expiry: Some(expiry),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.events[0].ty, "ActionWhitelisted");
+    let res = query(
+        deps.as_ref(),
+        mock_env(),
+        QueryMsg::GetPendingAction { action },
+    );
+    let pending: Option<PendingActionResponse> = from_binary(&res.unwrap()).unwrap();
+    assert_eq!(pending, None);
+}
+
+#[test]
+fn invalid_threshold() {
+    // Arrange
+    let mut deps = mock_dependencies();
+    let protocols = vec!["Protocol1".to_string()];
+    deps = setup(deps, protocols.clone());
+
+    let roles = ConfigureRoles {
+        proposers: vec![PROPOSER.to_string()],
+        threshold: 2,
+        guardians: vec![],
+    };
+    let encoded_message = encode(&roles).to_vec();
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(PROPOSER, &[]),
+        ExecuteMsg::WhitelistAction {
+            action: encoded_message.clone(),
+            expiry: None,
+        },
+    )
+    .unwrap();
+    let msg = ExecuteMsg::HandleCallMessage {
+        from: GOVERNANCE.to_string(),
+        data: encoded_message,
+        protocols: Some(protocols),
+    };
+
+    // Act
+    let res = execute(deps.as_mut(), mock_env(), mock_info(XCALL_ADDR, &[]), msg);
+
+    // Assert
+    assert_eq!(
+        res.unwrap_err(),
+        ContractError::InvalidThreshold {
+            threshold: 2,
+            proposers: 1
+        }
+    );
+}
+
+fn verify_protocol(deps: Deps<'_, Empty>, protocols: Vec<String>, valid: bool) {
+    let res = query(
+        deps,
+        mock_env(),
+        QueryMsg::VerifyProtocols {
+            protocols: protocols.clone(),
+        },
+    );
+    assert!(res.is_ok());
+    assert!(res.unwrap() == to_binary(&valid).unwrap());
+    if !valid {
+        return;
+    }
+
+    let res = query(
+        deps,
+        mock_env(),
+        QueryMsg::GetProtocols {
+            nid: NetId::from_str("icon").unwrap(),
+        },
+    );
     let current_protocols: ProtocolConfig = from_binary(&res.unwrap()).unwrap();
     assert!(current_protocols.sources == protocols);
 }
@@ -1,3 +1,4 @@
+use crate::network_address::AddressFormat;
 use crate::rate_limit::RateLimit;
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Uint128};
@@ -44,6 +45,19 @@ pub enum ExecuteMsg {
         percentage: u32,
     },
 
+    // local decimals of asset, amounts are scaled to and from the hub's 18 decimals
+    ConfigureAssetDecimals {
+        asset: String,
+        decimals: u8,
+    },
+
+    // overrides the format explicit deposit recipients on nid are checked against, None falls back
+    // to the format registered for the chain and recipients on chains without either are rejected
+    ConfigureRecipientFormat {
+        nid: String,
+        format: Option<AddressFormat>,
+    },
+
     HandleCallMessage {
         from: String,
         data: Vec<u8>,
@@ -62,6 +76,13 @@ pub enum QueryMsg {
     GetNetIds {},
     #[returns(RateLimit)]
     GetLimit { asset: String },
+    #[returns(u8)]
+    GetAssetDecimals { asset: String },
+    #[returns(Option<AddressFormat>)]
+    GetRecipientFormat { nid: String },
+    // total withdrawn hub amount below the asset's decimals that was not paid out
+    #[returns(Uint128)]
+    GetWithdrawDust { asset: String },
 }
 
 #[cw_serde]
@@ -4,8 +4,12 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Addr;
 use cw_ibc_rlp_lib::rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
 
+use crate::icon_rlp::{int_at, IconInt};
 use crate::network_address::NetworkAddress;
 
+pub const X_CROSS_TRANSFER: &str = "xCrossTransfer";
+pub const X_CROSS_TRANSFER_REVERT: &str = "xCrossTransferRevert";
+
 #[cw_serde]
 pub struct CrossTransfer {
     pub method: String,
@@ -20,6 +24,23 @@ pub struct CrossTransferRevert {
     pub method: String,
     pub from: Addr,
     pub value: u128,
+    // intended recipient of the reverted transfer, absent for legacy payloads
+    pub to: Option<NetworkAddress>,
+    // sha256 of the data attached to the reverted transfer
+    pub data_hash: Vec<u8>,
+    // nonce of the CrossTransfer this revert belongs to
+    pub nonce: u128,
+}
+
+pub const CONFIGURE_HUB: &str = "ConfigureHub";
+
+// governance update of the hub token configuration, empty fields keep the current value
+#[cw_serde]
+pub struct ConfigureHub {
+    pub method: String,
+    pub x_call: String,
+    pub hub_address: String,
+    pub manager: String,
 }
 
 impl Encodable for CrossTransfer {
@@ -29,7 +50,7 @@ impl Encodable for CrossTransfer {
             .append(&self.method)
             .append(&self.from.to_string())
             .append(&self.to.to_string())
-            .append(&self.value)
+            .append(&IconInt(self.value))
             .append(&self.data);
     }
 }
@@ -42,7 +63,7 @@ impl Decodable for CrossTransfer {
             method: rlp.val_at(0)?,
             from: NetworkAddress::from_str(&from).map_err(|_e| DecoderError::RlpInvalidLength)?,
             to: NetworkAddress::from_str(&to).map_err(|_e| DecoderError::RlpInvalidLength)?,
-            value: rlp.val_at(3)?,
+            value: int_at(rlp, 3)?,
             data: rlp.val_at(4)?,
         })
     }
@@ -50,21 +71,142 @@ impl Decodable for CrossTransfer {
 
 impl Encodable for CrossTransferRevert {
     fn rlp_append(&self, stream: &mut RlpStream) {
+        let to = self
+            .to
+            .as_ref()
+            .map(|to| to.to_string())
+            .unwrap_or_default();
         stream
-            .begin_list(3)
+            .begin_list(6)
             .append(&self.method)
             .append(&self.from.to_string())
-            .append(&self.value);
+            .append(&IconInt(self.value))
+            .append(&to)
+            .append(&self.data_hash)
+            .append(&IconInt(self.nonce));
     }
 }
 
 impl Decodable for CrossTransferRevert {
     fn decode(rlp: &Rlp<'_>) -> Result<Self, DecoderError> {
         let from: String = rlp.val_at(1)?;
+        // reverts of transfers sent before the context was added only carry 3 items
+        if rlp.item_count()? == 3 {
+            return Ok(Self {
+                method: rlp.val_at(0)?,
+                from: Addr::unchecked(from),
+                value: int_at(rlp, 2)?,
+                to: None,
+                data_hash: vec![],
+                nonce: 0,
+            });
+        }
+
+        let to: String = rlp.val_at(3)?;
+        let to = match to.is_empty() {
+            true => None,
+            false => {
+                Some(NetworkAddress::from_str(&to).map_err(|_e| DecoderError::RlpInvalidLength)?)
+            }
+        };
         Ok(Self {
             method: rlp.val_at(0)?,
             from: Addr::unchecked(from),
-            value: rlp.val_at(2)?,
+            value: int_at(rlp, 2)?,
+            to,
+            data_hash: rlp.val_at(4)?,
+            nonce: int_at(rlp, 5)?,
         })
     }
 }
+
+impl Encodable for ConfigureHub {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream
+            .begin_list(4)
+            .append(&self.method)
+            .append(&self.x_call)
+            .append(&self.hub_address)
+            .append(&self.manager);
+    }
+}
+
+impl Decodable for ConfigureHub {
+    fn decode(rlp: &Rlp<'_>) -> Result<Self, DecoderError> {
+        Ok(Self {
+            method: rlp.val_at(0)?,
+            x_call: rlp.val_at(1)?,
+            hub_address: rlp.val_at(2)?,
+            manager: rlp.val_at(3)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cw_ibc_rlp_lib::rlp::{decode, encode};
+
+    #[test]
+    fn test_cross_transfer_revert_round_trip() {
+        let revert = CrossTransferRevert {
+            method: "xCrossTransferRevert".to_string(),
+            from: Addr::unchecked("archway1sender"),
+            value: 100,
+            to: Some(
+                NetworkAddress::from_str("0x1.icon/hx9876543210fedcba9876543210fedcba98765432")
+                    .unwrap(),
+            ),
+            data_hash: vec![1, 2, 3],
+            nonce: 7,
+        };
+
+        let decoded: CrossTransferRevert = decode(&encode(&revert)).unwrap();
+        assert_eq!(decoded, revert);
+    }
+
+    #[test]
+    fn test_decode_legacy_cross_transfer_revert() {
+        let mut stream = RlpStream::new();
+        stream
+            .begin_list(3)
+            .append(&"xCrossTransferRevert".to_string())
+            .append(&"archway1sender".to_string())
+            .append(&100u128);
+
+        let decoded: CrossTransferRevert = decode(&stream.out()).unwrap();
+        assert_eq!(decoded.from, Addr::unchecked("archway1sender"));
+        assert_eq!(decoded.value, 100);
+        assert_eq!(decoded.to, None);
+        assert!(decoded.data_hash.is_empty());
+        assert_eq!(decoded.nonce, 0);
+    }
+
+    #[test]
+    fn test_decode_legacy_high_bit_cross_transfer_revert() {
+        let value = u128::MAX - 1;
+        let mut stream = RlpStream::new();
+        stream
+            .begin_list(3)
+            .append(&"xCrossTransferRevert".to_string())
+            .append(&"archway1sender".to_string())
+            .append(&value);
+
+        let decoded: CrossTransferRevert = decode(&stream.out()).unwrap();
+        assert_eq!(decoded.from, Addr::unchecked("archway1sender"));
+        assert_eq!(decoded.value, value);
+    }
+
+    #[test]
+    fn test_configure_hub_round_trip() {
+        let configure_hub = ConfigureHub {
+            method: CONFIGURE_HUB.to_string(),
+            x_call: "archway1xcall".to_string(),
+            hub_address: "0x1.icon/cx9876543210fedcba9876543210fedcba98765432".to_string(),
+            manager: "".to_string(),
+        };
+
+        let decoded: ConfigureHub = decode(&encode(&configure_hub)).unwrap();
+        assert_eq!(decoded, configure_hub);
+    }
+}
@@ -0,0 +1,87 @@
+use std::cmp::Ordering;
+
+use cosmwasm_std::{StdError, StdResult, Uint128};
+
+/// Decimals used for every asset amount encoded towards the hub.
+pub const HUB_DECIMALS: u8 = 18;
+
+fn scale_factor(from: u8, to: u8) -> StdResult<Uint128> {
+    let exp = from.abs_diff(to) as u32;
+    Ok(Uint128::new(10).checked_pow(exp)?)
+}
+
+/// Converts a local amount to its hub representation.
+/// Scaling up is exact; scaling down rejects amounts that would leave dust behind,
+/// since the remainder could never be bridged back.
+pub fn to_hub_amount(amount: u128, decimals: u8) -> StdResult<u128> {
+    let factor = scale_factor(decimals, HUB_DECIMALS)?;
+    let amount = Uint128::new(amount);
+    let converted = match decimals.cmp(&HUB_DECIMALS) {
+        Ordering::Equal => amount,
+        Ordering::Less => amount.checked_mul(factor)?,
+        Ordering::Greater => {
+            if !(amount % factor).is_zero() {
+                return Err(StdError::generic_err(format!(
+                    "Amount {amount} has dust below hub precision"
+                )));
+            }
+            amount / factor
+        }
+    };
+    Ok(converted.u128())
+}
+
+/// Converts a hub amount to its local representation.
+/// Scaling down rounds towards zero, callers account for the truncated dust.
+pub fn from_hub_amount(amount: u128, decimals: u8) -> StdResult<u128> {
+    let factor = scale_factor(decimals, HUB_DECIMALS)?;
+    let amount = Uint128::new(amount);
+    let converted = match decimals.cmp(&HUB_DECIMALS) {
+        Ordering::Equal => amount,
+        Ordering::Less => amount / factor,
+        Ordering::Greater => amount.checked_mul(factor)?,
+    };
+    Ok(converted.u128())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_decimals_is_identity() {
+        assert_eq!(to_hub_amount(1234, 18).unwrap(), 1234);
+        assert_eq!(from_hub_amount(1234, 18).unwrap(), 1234);
+    }
+
+    #[test]
+    fn fewer_decimals_scale_up_outbound() {
+        assert_eq!(
+            to_hub_amount(1_500_000, 6).unwrap(),
+            1_500_000_000_000_000_000
+        );
+        assert_eq!(
+            from_hub_amount(1_500_000_000_000_000_000, 6).unwrap(),
+            1_500_000
+        );
+    }
+
+    #[test]
+    fn fewer_decimals_round_down_inbound() {
+        assert_eq!(from_hub_amount(1_999_999_999_999, 6).unwrap(), 1);
+        assert_eq!(from_hub_amount(999_999_999_999, 6).unwrap(), 0);
+    }
+
+    #[test]
+    fn more_decimals_reject_outbound_dust() {
+        assert_eq!(to_hub_amount(5_000_000, 24).unwrap(), 5);
+        assert!(to_hub_amount(5_000_001, 24).is_err());
+        assert_eq!(from_hub_amount(5, 24).unwrap(), 5_000_000);
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        assert!(to_hub_amount(u128::MAX, 0).is_err());
+        assert!(from_hub_amount(u128::MAX, 24).is_err());
+    }
+}
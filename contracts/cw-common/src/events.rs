@@ -0,0 +1,160 @@
+use cosmwasm_std::{Addr, Event, Uint128};
+
+use crate::network_address::AddressFormat;
+use crate::rate_limit::RateLimit;
+use crate::xcall_manager_msg::ProtocolConfig;
+
+// shared event schema, every state-changing path of the spoke contracts emits one of these
+
+pub const DEPOSIT: &str = "Deposit";
+pub const WITHDRAW: &str = "Withdraw";
+pub const DEPOSIT_REVERT: &str = "DepositRevert";
+pub const RATE_LIMIT_UPDATED: &str = "RateLimitUpdated";
+pub const PROTOCOLS_CONFIGURED: &str = "ProtocolsConfigured";
+pub const GOVERNANCE_ACTION_EXECUTED: &str = "GovernanceActionExecuted";
+pub const XCALL_CONFIGURED: &str = "XCallConfigured";
+pub const NATIVE_TOKEN_CONFIGURED: &str = "NativeTokenConfigured";
+pub const ASSET_DECIMALS_CONFIGURED: &str = "AssetDecimalsConfigured";
+pub const RECIPIENT_FORMAT_CONFIGURED: &str = "RecipientFormatConfigured";
+
+// keys are kept capitalized for indexers that already consume the Deposit event
+pub fn deposit_event(token: &str, from: &Addr, to: &str, amount: Uint128) -> Event {
+    Event::new(DEPOSIT)
+        .add_attribute("Token", token)
+        .add_attribute("From", from.to_string())
+        .add_attribute("To", to)
+        .add_attribute("Amount", amount.to_string())
+}
+
+// dust is the part of the hub amount below the asset's decimals that was not paid out
+pub fn withdraw_event(
+    token: &str,
+    to: &str,
+    amount: Uint128,
+    dust: Uint128,
+    native: bool,
+) -> Event {
+    Event::new(WITHDRAW)
+        .add_attribute("token", token)
+        .add_attribute("to", to)
+        .add_attribute("amount", amount.to_string())
+        .add_attribute("dust", dust.to_string())
+        .add_attribute("native", native.to_string())
+}
+
+pub fn deposit_revert_event(token: &str, account: &str, amount: Uint128) -> Event {
+    Event::new(DEPOSIT_REVERT)
+        .add_attribute("token", token)
+        .add_attribute("account", account)
+        .add_attribute("amount", amount.to_string())
+}
+
+pub fn rate_limit_updated_event(asset: &str, limit: &RateLimit) -> Event {
+    Event::new(RATE_LIMIT_UPDATED)
+        .add_attribute("asset", asset)
+        .add_attribute("period", limit.period.to_string())
+        .add_attribute("percentage", limit.percentage.to_string())
+}
+
+pub fn protocols_configured_event(
+    nid: &str,
+    old: Option<ProtocolConfig>,
+    new: &ProtocolConfig,
+) -> Event {
+    let old = old.unwrap_or(ProtocolConfig {
+        sources: vec![],
+        destinations: vec![],
+    });
+    Event::new(PROTOCOLS_CONFIGURED)
+        .add_attribute("nid", nid)
+        .add_attribute("old_sources", old.sources.join(","))
+        .add_attribute("old_destinations", old.destinations.join(","))
+        .add_attribute("new_sources", new.sources.join(","))
+        .add_attribute("new_destinations", new.destinations.join(","))
+}
+
+// action_id is only set for actions released from the timelock queue
+pub fn governance_action_executed_event(method: &str, action_id: Option<u64>) -> Event {
+    let event = Event::new(GOVERNANCE_ACTION_EXECUTED).add_attribute("method", method);
+    match action_id {
+        Some(id) => event.add_attribute("action_id", id.to_string()),
+        None => event,
+    }
+}
+
+pub fn xcall_configured_event(xcall: &Addr, destination: &str, manager: &Addr) -> Event {
+    Event::new(XCALL_CONFIGURED)
+        .add_attribute("xcall", xcall.to_string())
+        .add_attribute("destination", destination)
+        .add_attribute("manager", manager.to_string())
+}
+
+pub fn native_token_configured_event(token: &Addr, manager: &Addr) -> Event {
+    Event::new(NATIVE_TOKEN_CONFIGURED)
+        .add_attribute("token", token.to_string())
+        .add_attribute("manager", manager.to_string())
+}
+
+pub fn asset_decimals_configured_event(asset: &str, decimals: u8) -> Event {
+    Event::new(ASSET_DECIMALS_CONFIGURED)
+        .add_attribute("asset", asset)
+        .add_attribute("decimals", decimals.to_string())
+}
+
+// format is "default" when the override is cleared and the chain's registered format applies
+pub fn recipient_format_configured_event(nid: &str, format: Option<&AddressFormat>) -> Event {
+    Event::new(RECIPIENT_FORMAT_CONFIGURED)
+        .add_attribute("nid", nid)
+        .add_attribute(
+            "format",
+            format.map_or("default".to_string(), |format| format.to_string()),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attribute<'a>(event: &'a Event, key: &str) -> &'a str {
+        event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == key)
+            .map(|attr| attr.value.as_str())
+            .unwrap()
+    }
+
+    #[test]
+    fn deposit_event_keeps_legacy_keys() {
+        let event = deposit_event(
+            "token1",
+            &Addr::unchecked("user"),
+            "0x01.icon/hx0000000000000000000000000000000000000001",
+            Uint128::new(100),
+        );
+        assert_eq!(event.ty, DEPOSIT);
+        assert_eq!(attribute(&event, "Token"), "token1");
+        assert_eq!(attribute(&event, "From"), "user");
+        assert_eq!(attribute(&event, "Amount"), "100");
+    }
+
+    #[test]
+    fn protocols_configured_event_defaults_old_config() {
+        let new = ProtocolConfig {
+            sources: vec!["a".to_string(), "b".to_string()],
+            destinations: vec!["c".to_string()],
+        };
+        let event = protocols_configured_event("0x1.icon", None, &new);
+        assert_eq!(attribute(&event, "old_sources"), "");
+        assert_eq!(attribute(&event, "new_sources"), "a,b");
+        assert_eq!(attribute(&event, "new_destinations"), "c");
+    }
+
+    #[test]
+    fn governance_action_executed_event_id() {
+        let event = governance_action_executed_event("Execute", None);
+        assert!(event.attributes.iter().all(|attr| attr.key != "action_id"));
+        let event = governance_action_executed_event("Execute", Some(3));
+        assert_eq!(attribute(&event, "action_id"), "3");
+    }
+}
@@ -1,15 +1,42 @@
-use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Addr, Binary, Uint128};
-use cw20::Expiration;
+use cw20::{
+    AllAccountsResponse, AllAllowancesResponse, AllSpenderAllowancesResponse, AllowanceResponse,
+    BalanceResponse, DownloadLogoResponse, Expiration, Logo, MarketingInfoResponse, MinterResponse,
+    TokenInfoResponse,
+};
+use cw20_base::msg::InstantiateMarketingInfo;
 
-use crate::network_address::NetworkAddress;
-pub use cw20_base::msg::{ExecuteMsg as Cw20ExecuteMsg, QueryMsg};
+use crate::network_address::{AddressFormat, NetworkAddress};
+pub use cw20_base::msg::ExecuteMsg as Cw20ExecuteMsg;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub x_call: String,
     pub hub_address: String,
     pub manager: Addr,
+    pub marketing: Option<InstantiateMarketingInfo>,
+    /// Token name, defaults to "Balanced Dollar".
+    pub name: Option<String>,
+    /// Token symbol, defaults to "bnUSD".
+    pub symbol: Option<String>,
+    /// Token decimals, defaults to 18.
+    pub decimals: Option<u8>,
+    /// xCall method names used towards the hub, default to "xCrossTransfer" and
+    /// "xCrossTransferRevert".
+    pub cross_transfer_method: Option<String>,
+    pub cross_transfer_revert_method: Option<String>,
+    /// Holds balances as a tokenfactory bank denom instead of cw20 balances when set.
+    pub token_factory: Option<TokenFactoryConfig>,
+}
+
+#[cw_serde]
+pub struct TokenFactoryConfig {
+    /// Subdenom created as `factory/{contract}/{subdenom}`.
+    pub subdenom: String,
+    /// Protobuf package of the chain's tokenfactory module, defaults to
+    /// "osmosis.tokenfactory.v1beta1".
+    pub proto_package: Option<String>,
 }
 
 #[cw_serde]
@@ -24,11 +51,20 @@ pub enum ExecuteMsg {
         data: Vec<u8>,
         protocols: Option<Vec<String>>,
     },
+    /// In tokenfactory mode the amount is paid in the bank denom through the attached funds.
     CrossTransfer {
         to: NetworkAddress,
         amount: u128,
         data: Vec<u8>,
     },
+    /// Only with "approval" extension. Bridges amount tokens from owner to the hub
+    /// if `env.sender` has sufficient pre-approval. Reverts are refunded to the owner.
+    CrossTransferFrom {
+        owner: String,
+        to: NetworkAddress,
+        amount: u128,
+        data: Vec<u8>,
+    },
     /// Transfer is a base message to move tokens to another account without triggering actions
     Transfer { recipient: String, amount: Uint128 },
     /// Burn is a base message to destroy tokens forever
@@ -80,8 +116,110 @@ pub enum ExecuteMsg {
     /// a new minter. Setting the minter to None will remove the
     /// token's minter forever.
     UpdateMinter { new_minter: Option<String> },
+    /// Only owner. Registers the token with the injective cw20 adapter, funds cover the
+    /// denom creation fee.
     #[cfg(feature = "injective")]
     SetAdapter { registry_contract: String },
+    /// Only owner. Halts transfers and cross transfers until unpaused.
+    Pause {},
+    /// Only owner. Resumes transfers and cross transfers.
+    Unpause {},
+    /// Only owner. Prevents address from sending, receiving or bridging tokens.
+    Block { address: String },
+    /// Only owner. Removes address from the blocklist.
+    Unblock { address: String },
+    /// Only owner. Overrides the address format cross transfer recipients on nid are checked
+    /// against. None falls back to the format registered for the chain, recipients on chains
+    /// without either are rejected.
+    ConfigureRecipientFormat {
+        nid: String,
+        format: Option<AddressFormat>,
+    },
+    /// Only owner. If project/description/marketing is Some, updates the field.
+    /// An empty string clears it.
+    UpdateMarketing {
+        project: Option<String>,
+        description: Option<String>,
+        marketing: Option<String>,
+    },
+    /// Only owner. Uploads a new logo for the token.
+    UploadLogo(Logo),
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Returns the current balance of the given address, 0 if unset.
+    #[returns(BalanceResponse)]
+    Balance { address: String },
+    /// Returns metadata on the contract - name, decimals, supply, etc.
+    #[returns(TokenInfoResponse)]
+    TokenInfo {},
+    /// Only with "mintable" extension.
+    /// Returns who can mint and the hard cap on maximum tokens after minting.
+    #[returns(MinterResponse)]
+    Minter {},
+    /// Only with "allowance" extension.
+    /// Returns how much spender can use from owner account, 0 if unset.
+    #[returns(AllowanceResponse)]
+    Allowance { owner: String, spender: String },
+    /// Only with "enumerable" extension (and "allowances")
+    /// Returns all allowances this owner has approved. Supports pagination.
+    #[returns(AllAllowancesResponse)]
+    AllAllowances {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Only with "enumerable" extension (and "allowances")
+    /// Returns all allowances this spender has been granted. Supports pagination.
+    #[returns(AllSpenderAllowancesResponse)]
+    AllSpenderAllowances {
+        spender: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Only with "enumerable" extension
+    /// Returns all accounts that have balances. Supports pagination.
+    #[returns(AllAccountsResponse)]
+    AllAccounts {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Only with "marketing" extension
+    /// Returns more metadata on the contract to display in the client.
+    #[returns(MarketingInfoResponse)]
+    MarketingInfo {},
+    /// Only with "marketing" extension
+    /// Downloads the embedded logo data (if stored on chain). Errors if no logo data is stored.
+    #[returns(DownloadLogoResponse)]
+    DownloadLogo {},
+    /// Returns whether transfers and cross transfers are paused.
+    #[returns(bool)]
+    IsPaused {},
+    /// Returns whether the address is on the blocklist.
+    #[returns(bool)]
+    IsBlocked { address: String },
+    /// Returns the rollback refund held for a blocked address in tokenfactory mode, paid out
+    /// when the address is unblocked.
+    #[returns(Uint128)]
+    FrozenRefund { address: String },
+    /// Returns the address format cross transfer recipients on nid are checked against.
+    #[returns(Option<AddressFormat>)]
+    GetRecipientFormat { nid: String },
+    /// Returns the tokenfactory denom if balances are held as a bank denom.
+    #[returns(Option<String>)]
+    Denom {},
+    /// Returns the injective cw20 adapter and the tokenfactory denom it mints.
+    #[cfg(feature = "injective")]
+    #[returns(AdapterResponse)]
+    GetAdapter {},
+}
+
+#[cw_serde]
+pub struct AdapterResponse {
+    pub adapter_contract: Addr,
+    pub denom: String,
 }
 
 #[cw_serde]
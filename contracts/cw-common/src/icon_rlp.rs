@@ -0,0 +1,102 @@
+use cw_ibc_rlp_lib::rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+
+// integer in the form ICON's rlp writer uses, which is BigInteger.toByteArray(): big endian
+// two's complement, so zero is a single 0x00 byte and values with the high bit set get a 0x00
+// prefix. Every field is unsigned, so decoding reads the bytes as a magnitude, which also accepts
+// the unsigned minimal form payloads were encoded with before, high bit set or not
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IconInt(pub u128);
+
+impl Encodable for IconInt {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        let bytes = self.0.to_be_bytes();
+        let start = bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(bytes.len() - 1);
+        let mut value = bytes[start..].to_vec();
+        if value[0] & 0x80 != 0 {
+            value.insert(0, 0);
+        }
+        stream.append(&value);
+    }
+}
+
+impl Decodable for IconInt {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        if rlp.is_list() {
+            return Err(DecoderError::RlpExpectedToBeData);
+        }
+        let bytes = rlp.data()?;
+        let start = bytes
+            .iter()
+            .position(|byte| *byte != 0)
+            .unwrap_or(bytes.len());
+        let bytes = &bytes[start..];
+        if bytes.len() > 16 {
+            return Err(DecoderError::Custom("Integer overflow"));
+        }
+        Ok(IconInt(
+            bytes
+                .iter()
+                .fold(0u128, |value, byte| value << 8 | *byte as u128),
+        ))
+    }
+}
+
+// decodes the integer at index into the field's type
+pub fn int_at<T: TryFrom<u128>>(rlp: &Rlp, index: usize) -> Result<T, DecoderError> {
+    let IconInt(value) = rlp.val_at(index)?;
+    T::try_from(value).map_err(|_| DecoderError::Custom("Integer overflow"))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::HexBinary;
+    use cw_ibc_rlp_lib::rlp::{decode, encode};
+
+    use super::*;
+
+    #[test]
+    fn encodes_like_big_integer() {
+        let cases: [(u128, &str); 7] = [
+            (0, "00"),
+            (1, "01"),
+            (0x7f, "7f"),
+            (0x80, "820080"),
+            (0xff, "8200ff"),
+            (0x8000, "83008000"),
+            (u128::MAX, "9100ffffffffffffffffffffffffffffffff"),
+        ];
+        for (value, expected) in cases {
+            let encoded = HexBinary::from(encode(&IconInt(value)).to_vec());
+            assert_eq!(encoded.to_hex(), expected, "{}", value);
+            let decoded: IconInt = decode(&encoded).unwrap();
+            assert_eq!(decoded, IconInt(value));
+        }
+    }
+
+    #[test]
+    fn decodes_unsigned_form() {
+        let zero: IconInt = decode(&[0x80]).unwrap();
+        assert_eq!(zero, IconInt(0));
+        let value: IconInt = decode(&[0x81, 0x80]).unwrap();
+        assert_eq!(value, IconInt(0x80));
+        let max: IconInt = decode(&encode(&u128::MAX)).unwrap();
+        assert_eq!(max, IconInt(u128::MAX));
+    }
+
+    #[test]
+    fn rejects_oversized() {
+        let mut oversized = vec![0x91, 0x01];
+        oversized.extend([0u8; 16]);
+        assert!(decode::<IconInt>(&oversized).is_err());
+
+        let mut stream = RlpStream::new_list(1);
+        stream.append(&IconInt(u64::MAX as u128 + 1));
+        let out = stream.out();
+        let rlp = Rlp::new(&out);
+        assert!(int_at::<u64>(&rlp, 0).is_err());
+        assert_eq!(int_at::<u128>(&rlp, 0).unwrap(), u64::MAX as u128 + 1);
+    }
+}
@@ -8,5 +8,10 @@ pub mod asset_manager_msg;
 pub mod xcall_data_types;
 pub mod xcall_manager_msg;
 
+pub mod decimals;
+pub mod events;
 pub mod helpers;
+pub mod icon_rlp;
+pub mod messages;
 pub mod rate_limit;
+pub mod xcall_client;
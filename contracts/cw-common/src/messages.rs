@@ -0,0 +1,321 @@
+use cosmwasm_schema::cw_serde;
+use cw_ibc_rlp_lib::rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+
+use crate::data_types::{
+    ConfigureHub, CrossTransfer, CrossTransferRevert, CONFIGURE_HUB, X_CROSS_TRANSFER,
+    X_CROSS_TRANSFER_REVERT,
+};
+use crate::xcall_data_types::{
+    Deposit, DepositRevert, WithdrawNativeTo, WithdrawTo, DEPOSIT, DEPOSIT_REVERT,
+    WITHDRAW_NATIVE_TO, WITHDRAW_TO,
+};
+use crate::xcall_manager_msg::{
+    ConfigureProtocols, ConfigureRemovalQuorum, ConfigureRoles, ConfigureTimelock,
+    GovernanceAction, BANK_SEND, BATCH, CONFIGURE_PROTOCOLS, CONFIGURE_REMOVAL_QUORUM,
+    CONFIGURE_ROLES, CONFIGURE_TIMELOCK, EXECUTE, MIGRATE, UPDATE_ADMIN,
+};
+
+// every rlp payload exchanged with the ICON side, dispatched on the method name in the first item.
+// the hub token can rename its cross transfer methods, only the default names decode here
+#[cw_serde]
+pub enum HubMessage {
+    Deposit(Deposit),
+    DepositRevert(DepositRevert),
+    WithdrawTo(WithdrawTo),
+    WithdrawNativeTo(WithdrawNativeTo),
+    CrossTransfer(CrossTransfer),
+    CrossTransferRevert(CrossTransferRevert),
+    ConfigureHub(ConfigureHub),
+    ConfigureProtocols(ConfigureProtocols),
+    ConfigureRoles(ConfigureRoles),
+    ConfigureRemovalQuorum(ConfigureRemovalQuorum),
+    ConfigureTimelock(ConfigureTimelock),
+    Governance(GovernanceAction),
+}
+
+impl HubMessage {
+    pub fn method(&self) -> &str {
+        match self {
+            HubMessage::Deposit(_) => DEPOSIT,
+            HubMessage::DepositRevert(_) => DEPOSIT_REVERT,
+            HubMessage::WithdrawTo(_) => WITHDRAW_TO,
+            HubMessage::WithdrawNativeTo(_) => WITHDRAW_NATIVE_TO,
+            HubMessage::CrossTransfer(cross_transfer) => &cross_transfer.method,
+            HubMessage::CrossTransferRevert(revert) => &revert.method,
+            HubMessage::ConfigureHub(configure_hub) => &configure_hub.method,
+            HubMessage::ConfigureProtocols(_) => CONFIGURE_PROTOCOLS,
+            HubMessage::ConfigureRoles(_) => CONFIGURE_ROLES,
+            HubMessage::ConfigureRemovalQuorum(_) => CONFIGURE_REMOVAL_QUORUM,
+            HubMessage::ConfigureTimelock(_) => CONFIGURE_TIMELOCK,
+            HubMessage::Governance(action) => match action {
+                GovernanceAction::Execute(_) => EXECUTE,
+                GovernanceAction::Migrate(_) => MIGRATE,
+                GovernanceAction::UpdateAdmin(_) => UPDATE_ADMIN,
+                GovernanceAction::BankSend(_) => BANK_SEND,
+                GovernanceAction::Batch(_) => BATCH,
+            },
+        }
+    }
+}
+
+impl Encodable for HubMessage {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        match self {
+            HubMessage::Deposit(msg) => msg.rlp_append(stream),
+            HubMessage::DepositRevert(msg) => msg.rlp_append(stream),
+            HubMessage::WithdrawTo(msg) => msg.rlp_append(stream),
+            HubMessage::WithdrawNativeTo(msg) => msg.rlp_append(stream),
+            HubMessage::CrossTransfer(msg) => msg.rlp_append(stream),
+            HubMessage::CrossTransferRevert(msg) => msg.rlp_append(stream),
+            HubMessage::ConfigureHub(msg) => msg.rlp_append(stream),
+            HubMessage::ConfigureProtocols(msg) => msg.rlp_append(stream),
+            HubMessage::ConfigureRoles(msg) => msg.rlp_append(stream),
+            HubMessage::ConfigureRemovalQuorum(msg) => msg.rlp_append(stream),
+            HubMessage::ConfigureTimelock(msg) => msg.rlp_append(stream),
+            HubMessage::Governance(action) => action.rlp_append(stream),
+        }
+    }
+}
+
+impl Decodable for HubMessage {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        if !rlp.is_list() {
+            return Err(DecoderError::RlpExpectedToBeList);
+        }
+        let method: String = rlp.val_at(0)?;
+        match method.as_str() {
+            DEPOSIT => Ok(HubMessage::Deposit(Deposit::decode(rlp)?)),
+            DEPOSIT_REVERT => Ok(HubMessage::DepositRevert(DepositRevert::decode(rlp)?)),
+            WITHDRAW_TO => Ok(HubMessage::WithdrawTo(WithdrawTo::decode(rlp)?)),
+            WITHDRAW_NATIVE_TO => Ok(HubMessage::WithdrawNativeTo(WithdrawNativeTo::decode(rlp)?)),
+            X_CROSS_TRANSFER => Ok(HubMessage::CrossTransfer(CrossTransfer::decode(rlp)?)),
+            X_CROSS_TRANSFER_REVERT => Ok(HubMessage::CrossTransferRevert(
+                CrossTransferRevert::decode(rlp)?,
+            )),
+            CONFIGURE_HUB => Ok(HubMessage::ConfigureHub(ConfigureHub::decode(rlp)?)),
+            CONFIGURE_PROTOCOLS => Ok(HubMessage::ConfigureProtocols(ConfigureProtocols::decode(
+                rlp,
+            )?)),
+            CONFIGURE_ROLES => Ok(HubMessage::ConfigureRoles(ConfigureRoles::decode(rlp)?)),
+            CONFIGURE_REMOVAL_QUORUM => Ok(HubMessage::ConfigureRemovalQuorum(
+                ConfigureRemovalQuorum::decode(rlp)?,
+            )),
+            CONFIGURE_TIMELOCK => Ok(HubMessage::ConfigureTimelock(ConfigureTimelock::decode(
+                rlp,
+            )?)),
+            EXECUTE | MIGRATE | UPDATE_ADMIN | BANK_SEND | BATCH => {
+                Ok(HubMessage::Governance(GovernanceAction::decode(rlp)?))
+            }
+            _ => Err(DecoderError::Custom("Unknown method")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{coin, Addr};
+    use cw_ibc_rlp_lib::rlp::{decode, encode};
+
+    use super::*;
+    use crate::network_address::NetworkAddress;
+    use crate::xcall_manager_msg::{BankSend, Batch, Execute, Migrate, UpdateAdmin};
+
+    // small deterministic generator so the round trip is checked over many inputs without
+    // pulling in a property testing crate
+    struct Gen(u64);
+
+    impl Gen {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn amount(&mut self) -> u128 {
+            match self.next() % 4 {
+                0 => 0,
+                1 => u128::MAX,
+                _ => ((self.next() as u128) << 64) | self.next() as u128,
+            }
+        }
+
+        fn string(&mut self) -> String {
+            let len = self.next() % 48;
+            (0..len)
+                .map(|_| char::from(b'!' + (self.next() % 94) as u8))
+                .collect()
+        }
+
+        fn bytes(&mut self) -> Vec<u8> {
+            let len = self.next() % 80;
+            (0..len).map(|_| self.next() as u8).collect()
+        }
+
+        fn strings(&mut self) -> Vec<String> {
+            (0..self.next() % 4).map(|_| self.string()).collect()
+        }
+
+        fn network_address(&mut self) -> NetworkAddress {
+            NetworkAddress::new(&format!("0x{:x}.icon", self.next() % 16), &self.hex())
+        }
+
+        fn hex(&mut self) -> String {
+            format!("hx{:040x}", self.next())
+        }
+    }
+
+    fn messages(gen: &mut Gen) -> Vec<HubMessage> {
+        vec![
+            HubMessage::Deposit(Deposit {
+                token_address: gen.string(),
+                from: gen.string(),
+                to: gen.string(),
+                amount: gen.amount(),
+                data: gen.bytes(),
+            }),
+            HubMessage::DepositRevert(DepositRevert {
+                token_address: gen.string(),
+                account: gen.string(),
+                amount: gen.amount(),
+            }),
+            HubMessage::WithdrawTo(WithdrawTo {
+                token_address: gen.string(),
+                user_address: gen.string(),
+                amount: gen.amount(),
+            }),
+            HubMessage::WithdrawNativeTo(WithdrawNativeTo {
+                token_address: gen.string(),
+                user_address: gen.string(),
+                amount: gen.amount(),
+            }),
+            HubMessage::CrossTransfer(CrossTransfer {
+                method: X_CROSS_TRANSFER.to_string(),
+                from: gen.network_address(),
+                to: gen.network_address(),
+                value: gen.amount(),
+                data: gen.bytes(),
+            }),
+            HubMessage::CrossTransferRevert(CrossTransferRevert {
+                method: X_CROSS_TRANSFER_REVERT.to_string(),
+                from: Addr::unchecked(gen.hex()),
+                value: gen.amount(),
+                to: Some(gen.network_address()),
+                data_hash: gen.bytes(),
+                nonce: gen.amount(),
+            }),
+            HubMessage::ConfigureHub(ConfigureHub {
+                method: CONFIGURE_HUB.to_string(),
+                x_call: gen.string(),
+                hub_address: gen.string(),
+                manager: gen.string(),
+            }),
+            HubMessage::ConfigureProtocols(ConfigureProtocols {
+                sources: gen.strings(),
+                destinations: gen.strings(),
+                nid: Some(gen.string()),
+            }),
+            HubMessage::ConfigureRoles(ConfigureRoles {
+                proposers: gen.strings(),
+                threshold: gen.next() as u32,
+                guardians: gen.strings(),
+            }),
+            HubMessage::ConfigureRemovalQuorum(ConfigureRemovalQuorum {
+                quorum: gen.next() as u32,
+            }),
+            HubMessage::ConfigureTimelock(ConfigureTimelock {
+                timelock: gen.next(),
+            }),
+            HubMessage::Governance(GovernanceAction::Execute(Execute {
+                contract_addr: gen.string(),
+                message: gen.string(),
+                funds: Some(vec![coin(gen.next() as u128, "arch")]),
+            })),
+            HubMessage::Governance(GovernanceAction::Migrate(Migrate {
+                contract_addr: gen.string(),
+                code_id: gen.next(),
+                message: gen.string(),
+            })),
+            HubMessage::Governance(GovernanceAction::UpdateAdmin(UpdateAdmin {
+                contract_addr: gen.string(),
+                admin: gen.string(),
+            })),
+            HubMessage::Governance(GovernanceAction::Batch(Batch {
+                actions: vec![GovernanceAction::BankSend(BankSend {
+                    to_address: gen.string(),
+                    amount: vec![coin(gen.amount(), "arch")],
+                })],
+            })),
+        ]
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut gen = Gen(0x9e3779b97f4a7c15);
+        for _ in 0..64 {
+            for message in messages(&mut gen) {
+                let encoded = encode(&message);
+                let decoded: HubMessage = decode(&encoded).unwrap();
+                assert_eq!(decoded, message);
+                assert_eq!(encode(&decoded), encoded);
+
+                let method: String = Rlp::new(&encoded).val_at(0).unwrap();
+                assert_eq!(method, message.method());
+            }
+        }
+    }
+
+    #[test]
+    fn withdraw_native_to_keeps_its_variant() {
+        let withdraw = WithdrawNativeTo {
+            token_address: "token".to_string(),
+            user_address: "user".to_string(),
+            amount: 1000,
+        };
+
+        let decoded: HubMessage = decode(&encode(&withdraw)).unwrap();
+        assert_eq!(decoded, HubMessage::WithdrawNativeTo(withdraw));
+    }
+
+    #[test]
+    fn decode_legacy_cross_transfer_revert() {
+        let mut stream = RlpStream::new();
+        stream
+            .begin_list(3)
+            .append(&X_CROSS_TRANSFER_REVERT.to_string())
+            .append(&"archway1sender".to_string())
+            .append(&100u128);
+
+        let decoded: HubMessage = decode(&stream.out()).unwrap();
+        assert!(matches!(
+            decoded,
+            HubMessage::CrossTransferRevert(CrossTransferRevert { to: None, .. })
+        ));
+    }
+
+    #[test]
+    fn decode_rejects_invalid_payloads() {
+        let mut stream = RlpStream::new();
+        stream
+            .begin_list(3)
+            .append(&WITHDRAW_TO.to_string())
+            .append(&"token".to_string())
+            .append(&"user".to_string());
+        assert!(matches!(
+            decode::<HubMessage>(&stream.out()),
+            Err(DecoderError::RlpInvalidLength)
+        ));
+
+        let mut stream = RlpStream::new();
+        stream.begin_list(1).append(&"Unknown".to_string());
+        assert!(matches!(
+            decode::<HubMessage>(&stream.out()),
+            Err(DecoderError::Custom("Unknown method"))
+        ));
+
+        assert!(matches!(
+            decode::<HubMessage>(&encode(&WITHDRAW_TO.to_string())),
+            Err(DecoderError::RlpExpectedToBeList)
+        ));
+    }
+}
@@ -1,5 +1,9 @@
+use bech32::Variant;
+use cosmwasm_schema::cw_serde;
 pub use cosmwasm_std::Addr;
 pub use cw_xcall_lib::network_address::{NetId, NetworkAddress};
+use sha3::{Digest, Keccak256};
+use std::fmt;
 pub use std::str::FromStr;
 
 pub trait IconAddressValidation {
@@ -9,16 +13,93 @@ pub trait IconAddressValidation {
 impl IconAddressValidation for NetworkAddress {
     fn validate_foreign_addresses(&self) -> bool {
         let parts = self.get_parts();
-        let net_id = parts[0].to_string();
-        let address = parts[1];
-        match net_id {
-            s if s.contains("icon") => validate_icon_address(address),
-            _ => false,
+        validate_foreign_address(parts[0], parts[1])
+    }
+}
+
+// address formats of the chains spokes and hubs can be configured against
+#[cw_serde]
+pub enum AddressFormat {
+    Icon,
+    // 0x + 40 hex, mixed case addresses must carry a valid EIP-55 checksum
+    Evm,
+    Bech32 { prefix: String },
+    Solana,
+    Sui,
+    Stellar,
+}
+
+impl AddressFormat {
+    pub fn validate(&self, address: &str) -> bool {
+        match self {
+            AddressFormat::Icon => validate_icon_address(address),
+            AddressFormat::Evm => validate_evm_address(address),
+            AddressFormat::Bech32 { prefix } => validate_bech32_address(address, prefix),
+            AddressFormat::Solana => validate_solana_address(address),
+            AddressFormat::Sui => validate_sui_address(address),
+            AddressFormat::Stellar => validate_stellar_address(address),
         }
     }
 }
 
-fn validate_icon_address(address: &str) -> bool {
+impl fmt::Display for AddressFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressFormat::Icon => write!(f, "icon"),
+            AddressFormat::Evm => write!(f, "evm"),
+            AddressFormat::Bech32 { prefix } => write!(f, "bech32:{prefix}"),
+            AddressFormat::Solana => write!(f, "solana"),
+            AddressFormat::Sui => write!(f, "sui"),
+            AddressFormat::Stellar => write!(f, "stellar"),
+        }
+    }
+}
+
+// chain name of a network id, "0x38.bsc" -> "bsc" and "archway-1" -> "archway"
+pub fn chain_name(nid: &str) -> &str {
+    let chain = nid.rsplit('.').next().unwrap_or(nid);
+    chain.split('-').next().unwrap_or(chain)
+}
+
+// registry of known chains, keyed on the chain name of the nid
+pub fn address_format(nid: &str) -> Option<AddressFormat> {
+    let bech32 = |prefix: &str| AddressFormat::Bech32 {
+        prefix: prefix.to_string(),
+    };
+    let format = match chain_name(nid) {
+        "icon" => AddressFormat::Icon,
+        "eth" | "ethereum" | "bsc" | "avax" | "arbitrum" | "base" | "optimism" | "polygon" => {
+            AddressFormat::Evm
+        }
+        "archway" | "constantine" => bech32("archway"),
+        "injective" => bech32("inj"),
+        "neutron" | "pion" => bech32("neutron"),
+        "osmosis" | "osmo" => bech32("osmo"),
+        "solana" => AddressFormat::Solana,
+        "sui" => AddressFormat::Sui,
+        "stellar" => AddressFormat::Stellar,
+        _ => return None,
+    };
+    Some(format)
+}
+
+// addresses on chains missing from the registry are rejected
+pub fn validate_foreign_address(nid: &str, address: &str) -> bool {
+    address_format(nid).map_or(false, |format| format.validate(address))
+}
+
+// format recipients on nid are checked against, a configured override wins over the registry
+pub fn recipient_format(nid: &str, configured: Option<AddressFormat>) -> Option<AddressFormat> {
+    configured.or_else(|| address_format(nid))
+}
+
+// recipients on chains without a known format are rejected until the owner configures one
+pub fn validate_recipient(to: &NetworkAddress, configured: Option<AddressFormat>) -> bool {
+    let parts = to.get_parts();
+    recipient_format(parts[0], configured).map_or(false, |format| format.validate(parts[1]))
+}
+
+pub fn validate_icon_address(address: &str) -> bool {
     let lowercase_address = address.to_lowercase();
 
     if !lowercase_address.starts_with("hx") && !lowercase_address.starts_with("cx") {
@@ -28,10 +109,113 @@ fn validate_icon_address(address: &str) -> bool {
     lowercase_address.len() == 42 && is_valid_character_set(&lowercase_address[2..])
 }
 
+pub fn validate_evm_address(address: &str) -> bool {
+    let hex = match address.strip_prefix("0x") {
+        Some(hex) if hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit()) => hex,
+        _ => return false,
+    };
+    // single case addresses carry no checksum
+    if hex == hex.to_lowercase() || hex == hex.to_uppercase() {
+        return true;
+    }
+
+    let hash = Keccak256::digest(hex.to_lowercase().as_bytes());
+    hex.chars().enumerate().all(|(i, c)| {
+        let nibble = if i % 2 == 0 {
+            hash[i / 2] >> 4
+        } else {
+            hash[i / 2] & 0x0f
+        };
+        !c.is_ascii_alphabetic() || c.is_ascii_uppercase() == (nibble >= 8)
+    })
+}
+
+// 20 byte account or 32 byte contract addresses
+pub fn validate_bech32_address(address: &str, prefix: &str) -> bool {
+    match bech32::decode(address) {
+        Ok((hrp, data, Variant::Bech32)) => hrp == prefix && matches!(data.len(), 32 | 52),
+        _ => false,
+    }
+}
+
+// base58 encoded 32 byte public key
+pub fn validate_solana_address(address: &str) -> bool {
+    matches!(address.len(), 32..=44) && base58_decode(address).map_or(false, |key| key.len() == 32)
+}
+
+pub fn validate_sui_address(address: &str) -> bool {
+    address.strip_prefix("0x").map_or(false, |hex| {
+        hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+// strkey encoded account (G...) or contract (C...) with its crc16 checksum
+pub fn validate_stellar_address(address: &str) -> bool {
+    const ACCOUNT: u8 = 6 << 3;
+    const CONTRACT: u8 = 2 << 3;
+
+    let decoded = match base32_decode(address) {
+        Some(decoded) if address.len() == 56 && decoded.len() == 35 => decoded,
+        _ => return false,
+    };
+    let (payload, checksum) = decoded.split_at(33);
+    matches!(payload[0], ACCOUNT | CONTRACT)
+        && crc16_xmodem(payload).to_le_bytes() == [checksum[0], checksum[1]]
+}
+
 fn is_valid_character_set(address: &str) -> bool {
     address.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
 }
 
+fn base58_decode(input: &str) -> Option<Vec<u8>> {
+    const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    let mut bytes: Vec<u8> = vec![];
+    for c in input.bytes() {
+        let mut carry = ALPHABET.iter().position(|a| *a == c)? as u32;
+        for byte in bytes.iter_mut().rev() {
+            carry += *byte as u32 * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.insert(0, carry as u8);
+            carry >>= 8;
+        }
+    }
+    let zeros = input.bytes().take_while(|c| *c == b'1').count();
+    Some([vec![0; zeros], bytes].concat())
+}
+
+fn base32_decode(input: &str) -> Option<Vec<u8>> {
+    let mut bytes = vec![];
+    let (mut buffer, mut bits) = (0u32, 0);
+    for c in input.bytes() {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(bytes)
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    data.iter().fold(0u16, |crc, byte| {
+        (0..8).fold(crc ^ ((*byte as u16) << 8), |crc, _| match crc & 0x8000 {
+            0 => crc << 1,
+            _ => (crc << 1) ^ 0x1021,
+        })
+    })
+}
+
 #[test]
 fn test_parse_btp_address() {
     let network_address =
@@ -75,3 +259,90 @@ fn address_validation_test() {
     let res = network_address.validate_foreign_addresses();
     assert!(!res);
 }
+
+#[test]
+fn foreign_address_registry_test() {
+    let valid = [
+        ("0x1.icon", "hx9876543210fedcba9876543210fedcba98765432"),
+        ("0x38.bsc", "0x52908400098527886E0F7030069857D2E4169EE7"),
+        ("0x1.eth", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+        (
+            "0xa4b1.arbitrum",
+            "0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359",
+        ),
+        (
+            "archway-1",
+            "archway1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc52fs6vt",
+        ),
+        (
+            "archway-1",
+            "archway1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0shd8x93",
+        ),
+        ("injective-1", "inj1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc54tm65y"),
+        ("solana", "4wBqpZM9xaSheZzJSMawUKKwhdpChKbZ5eu5ky4Vigw"),
+        ("solana", "11111111111111111111111111111111"),
+        (
+            "sui",
+            "0x0000000000000000000000000000000000000000000000000000000000000002",
+        ),
+        (
+            "stellar",
+            "GAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB7JZX",
+        ),
+        (
+            "stellar",
+            "CAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB6N4O",
+        ),
+    ];
+    for (nid, address) in valid {
+        assert!(validate_foreign_address(nid, address), "{nid}/{address}");
+    }
+
+    let invalid = [
+        // bad eip-55 checksum
+        ("0x1.eth", "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+        ("0x38.bsc", "0x52908400098527886E0F7030069857D2E4169EE"),
+        // wrong hrp and broken checksum
+        ("archway-1", "inj1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc54tm65y"),
+        (
+            "archway-1",
+            "archway1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc52fs6vu",
+        ),
+        // 31 byte key
+        ("solana", "thX6LZfHDZZKUs92febYZhYRcXddmzfzF2NvTkPNE"),
+        ("solana", "0OIl1111111111111111111111111111"),
+        ("sui", "0x02"),
+        (
+            "stellar",
+            "GAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB7JZY",
+        ),
+        ("0x1.unknown", "anything"),
+    ];
+    for (nid, address) in invalid {
+        assert!(!validate_foreign_address(nid, address), "{nid}/{address}");
+    }
+}
+
+#[test]
+fn recipient_validation_test() {
+    let evm =
+        NetworkAddress::from_str("0x1.eth/0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD").unwrap();
+    assert!(!validate_recipient(&evm, None));
+
+    let cosmos =
+        NetworkAddress::from_str("injective-1/archway1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc52fs6vt")
+            .unwrap();
+    assert!(!validate_recipient(&cosmos, None));
+    let format = AddressFormat::Bech32 {
+        prefix: "archway".to_string(),
+    };
+    assert!(validate_recipient(&cosmos, Some(format)));
+
+    let unknown = NetworkAddress::from_str("0x1.unknown/anything").unwrap();
+    assert!(!validate_recipient(&unknown, None));
+    assert!(!validate_recipient(&unknown, Some(AddressFormat::Evm)));
+    let unknown =
+        NetworkAddress::from_str("0x1.unknown/0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").unwrap();
+    assert!(!validate_recipient(&unknown, None));
+    assert!(validate_recipient(&unknown, Some(AddressFormat::Evm)));
+}
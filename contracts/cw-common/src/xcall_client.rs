@@ -0,0 +1,191 @@
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Uint128};
+use cw_xcall_lib::network_address::{NetId, NetworkAddress};
+use cw_xcall_multi::msg::QueryMsg as XCallQueryMsg;
+
+use crate::xcall_manager_msg::{ProtocolConfig, QueryMsg as XCallManagerQueryMsg};
+
+// typed queries against the xcall contract
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XCallClient {
+    addr: Addr,
+}
+
+impl XCallClient {
+    pub fn new(addr: Addr) -> Self {
+        Self { addr }
+    }
+
+    pub fn addr(&self) -> &Addr {
+        &self.addr
+    }
+
+    pub fn network_address(&self, querier: &QuerierWrapper) -> StdResult<NetworkAddress> {
+        querier.query_wasm_smart(&self.addr, &XCallQueryMsg::GetNetworkAddress {})
+    }
+
+    pub fn fee(
+        &self,
+        querier: &QuerierWrapper,
+        nid: NetId,
+        rollback: bool,
+        sources: Option<Vec<String>>,
+    ) -> StdResult<u128> {
+        let fee: Uint128 = querier.query_wasm_smart(
+            &self.addr,
+            &XCallQueryMsg::GetFee {
+                nid,
+                rollback,
+                sources,
+            },
+        )?;
+        Ok(fee.u128())
+    }
+}
+
+// typed queries against the xcall manager contract
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XCallManagerClient {
+    addr: Addr,
+}
+
+impl XCallManagerClient {
+    pub fn new(addr: Addr) -> Self {
+        Self { addr }
+    }
+
+    pub fn addr(&self) -> &Addr {
+        &self.addr
+    }
+
+    // a missing protocol list is checked as an empty one
+    pub fn verify_protocols(
+        &self,
+        querier: &QuerierWrapper,
+        protocols: Option<Vec<String>>,
+    ) -> StdResult<bool> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &XCallManagerQueryMsg::VerifyProtocols {
+                protocols: protocols.unwrap_or_default(),
+            },
+        )
+    }
+
+    pub fn protocols(&self, querier: &QuerierWrapper, nid: NetId) -> StdResult<ProtocolConfig> {
+        querier.query_wasm_smart(&self.addr, &XCallManagerQueryMsg::GetProtocols { nid })
+    }
+
+    pub fn governance(&self, querier: &QuerierWrapper) -> StdResult<String> {
+        querier.query_wasm_smart(&self.addr, &XCallManagerQueryMsg::GetGovernance {})
+    }
+}
+
+#[cfg(any(test, feature = "mock"))]
+pub mod mock {
+    use cosmwasm_std::{from_binary, to_binary, QuerierResult, SystemResult, Uint128, WasmQuery};
+    use cw_xcall_multi::msg::QueryMsg as XCallQueryMsg;
+
+    use crate::xcall_manager_msg::{ProtocolConfig, QueryMsg as XCallManagerQueryMsg};
+
+    // canned xcall and xcall manager responses for MockQuerier::update_wasm
+    #[derive(Clone, Debug)]
+    pub struct MockXCall {
+        pub xcall: String,
+        pub network_address: String,
+        pub fee: u128,
+        pub manager: String,
+        pub protocols: ProtocolConfig,
+        pub governance: String,
+        pub verified: bool,
+    }
+
+    impl MockXCall {
+        pub fn new(xcall: &str, network_address: &str, manager: &str) -> Self {
+            Self {
+                xcall: xcall.to_string(),
+                network_address: network_address.to_string(),
+                fee: 0,
+                manager: manager.to_string(),
+                protocols: ProtocolConfig {
+                    sources: vec![],
+                    destinations: vec![],
+                },
+                governance: String::new(),
+                verified: true,
+            }
+        }
+
+        // answers the queries the clients send, anything else is left to the caller's handler
+        pub fn query(&self, request: &WasmQuery) -> Option<QuerierResult> {
+            let (contract_addr, msg) = match request {
+                WasmQuery::Smart { contract_addr, msg } => (contract_addr, msg),
+                _ => return None,
+            };
+            let response = if contract_addr == &self.xcall {
+                match from_binary(msg).ok()? {
+                    XCallQueryMsg::GetNetworkAddress {} => to_binary(&self.network_address),
+                    XCallQueryMsg::GetFee { .. } => to_binary(&Uint128::new(self.fee)),
+                    _ => return None,
+                }
+            } else if contract_addr == &self.manager {
+                match from_binary(msg).ok()? {
+                    XCallManagerQueryMsg::VerifyProtocols { .. } => to_binary(&self.verified),
+                    XCallManagerQueryMsg::GetProtocols { .. } => to_binary(&self.protocols),
+                    XCallManagerQueryMsg::GetGovernance {} => to_binary(&self.governance),
+                    _ => return None,
+                }
+            } else {
+                return None;
+            };
+            Some(SystemResult::Ok(response.into()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_dependencies;
+    use cosmwasm_std::{to_binary, ContractResult, SystemResult};
+    use std::str::FromStr;
+
+    use super::mock::MockXCall;
+    use super::*;
+
+    #[test]
+    fn clients_query_mocked_contracts() {
+        let mut deps = mock_dependencies();
+        let mut mock = MockXCall::new("xcall", "0x44.archway/xcall", "manager");
+        mock.fee = 10;
+        mock.protocols.sources = vec!["centralized".to_string()];
+        mock.governance = "0x01.icon/cx0000000000000000000000000000000000000001".to_string();
+        mock.verified = false;
+        deps.querier.update_wasm(move |request| {
+            mock.query(request)
+                .unwrap_or(SystemResult::Ok(ContractResult::Ok(to_binary(&0).unwrap())))
+        });
+        let querier = deps.as_ref().querier;
+
+        let xcall = XCallClient::new(Addr::unchecked("xcall"));
+        assert_eq!(
+            xcall.network_address(&querier).unwrap().to_string(),
+            "0x44.archway/xcall"
+        );
+        let nid = NetId::from_str("0x1.icon").unwrap();
+        assert_eq!(xcall.fee(&querier, nid.clone(), true, None).unwrap(), 10);
+
+        let manager = XCallManagerClient::new(Addr::unchecked("manager"));
+        assert!(!manager.verify_protocols(&querier, None).unwrap());
+        assert_eq!(
+            manager.protocols(&querier, nid).unwrap().sources,
+            vec!["centralized".to_string()]
+        );
+        assert_eq!(
+            manager.governance(&querier).unwrap(),
+            "0x01.icon/cx0000000000000000000000000000000000000001"
+        );
+
+        // queries the mock does not know about fall through to the caller
+        let other = XCallClient::new(Addr::unchecked("other"));
+        assert!(other.network_address(&querier).is_err());
+    }
+}
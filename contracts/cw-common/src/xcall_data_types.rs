@@ -1,5 +1,12 @@
 use cosmwasm_schema::cw_serde;
-use cw_ibc_rlp_lib::rlp::{Encodable, RlpStream};
+use cw_ibc_rlp_lib::rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+
+use crate::icon_rlp::{int_at, IconInt};
+
+pub const DEPOSIT: &str = "Deposit";
+pub const DEPOSIT_REVERT: &str = "DepositRevert";
+pub const WITHDRAW_TO: &str = "WithdrawTo";
+pub const WITHDRAW_NATIVE_TO: &str = "WithdrawNativeTo";
 
 //for testing
 #[cw_serde]
@@ -39,47 +46,100 @@ impl Encodable for Deposit {
     //specify the encoding logic for struct's fields so that rlp_bytes() can alo use
     fn rlp_append(&self, s: &mut RlpStream) {
         //append struct's each field to stream object
-        let method = "Deposit".to_string();
+        let method = DEPOSIT.to_string();
         s.begin_list(6)
             .append(&method)
             .append(&self.token_address)
             .append(&self.from)
             .append(&self.to)
-            .append(&self.amount)
+            .append(&IconInt(self.amount))
             .append(&self.data);
     }
 }
 
 impl Encodable for DepositRevert {
     fn rlp_append(&self, s: &mut RlpStream) {
-        let method = "DepositRevert".to_string();
+        let method = DEPOSIT_REVERT.to_string();
         s.begin_list(4)
             .append(&method)
             .append(&self.token_address)
             .append(&self.account)
-            .append(&self.amount);
+            .append(&IconInt(self.amount));
     }
 }
 
 impl Encodable for WithdrawTo {
     fn rlp_append(&self, s: &mut RlpStream) {
-        let method = "WithdrawTo".to_string();
+        let method = WITHDRAW_TO.to_string();
         s.begin_list(4)
             .append(&method)
             .append(&self.token_address)
             .append(&self.user_address)
-            .append(&self.amount);
+            .append(&IconInt(self.amount));
     }
 }
 
 impl Encodable for WithdrawNativeTo {
     fn rlp_append(&self, s: &mut RlpStream) {
-        let method = "WithdrawNativeTo".to_string();
+        let method = WITHDRAW_NATIVE_TO.to_string();
         s.begin_list(4)
             .append(&method)
             .append(&self.token_address)
             .append(&self.user_address)
-            .append(&self.amount);
+            .append(&IconInt(self.amount));
+    }
+}
+
+fn ensure_item_count(rlp: &Rlp, count: usize) -> Result<(), DecoderError> {
+    if rlp.item_count()? != count {
+        return Err(DecoderError::RlpInvalidLength);
+    }
+    Ok(())
+}
+
+impl Decodable for Deposit {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        ensure_item_count(rlp, 6)?;
+        Ok(Self {
+            token_address: rlp.val_at(1)?,
+            from: rlp.val_at(2)?,
+            to: rlp.val_at(3)?,
+            amount: int_at(rlp, 4)?,
+            data: rlp.val_at(5)?,
+        })
+    }
+}
+
+impl Decodable for DepositRevert {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        ensure_item_count(rlp, 4)?;
+        Ok(Self {
+            token_address: rlp.val_at(1)?,
+            account: rlp.val_at(2)?,
+            amount: int_at(rlp, 3)?,
+        })
+    }
+}
+
+impl Decodable for WithdrawTo {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        ensure_item_count(rlp, 4)?;
+        Ok(Self {
+            token_address: rlp.val_at(1)?,
+            user_address: rlp.val_at(2)?,
+            amount: int_at(rlp, 3)?,
+        })
+    }
+}
+
+impl Decodable for WithdrawNativeTo {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        ensure_item_count(rlp, 4)?;
+        Ok(Self {
+            token_address: rlp.val_at(1)?,
+            user_address: rlp.val_at(2)?,
+            amount: int_at(rlp, 3)?,
+        })
     }
 }
 
@@ -126,4 +186,21 @@ mod tests {
         //checking if encoded structs are different
         assert_ne!(encoded_deposit, encode_deposit_revert);
     }
+
+    #[test]
+    fn test_decode_legacy_high_bit_deposit_revert() {
+        let amount = 1u128 << 127;
+        let mut stream = RlpStream::new();
+        stream
+            .begin_list(4)
+            .append(&DEPOSIT_REVERT.to_string())
+            .append(&"contract1".to_string())
+            .append(&"sender".to_string())
+            .append(&amount);
+
+        let decoded: DepositRevert = cw_ibc_rlp_lib::rlp::decode(&stream.out()).unwrap();
+        assert_eq!(decoded.token_address, "contract1");
+        assert_eq!(decoded.account, "sender");
+        assert_eq!(decoded.amount, amount);
+    }
 }
@@ -1,14 +1,21 @@
 use std::vec;
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::Addr;
+use cosmwasm_std::{coin, Addr, Coin, HexBinary, Timestamp};
+use cw20::Expiration;
 use cw_ibc_rlp_lib::rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+use cw_xcall_lib::network_address::NetId;
+
+use crate::icon_rlp::{int_at, IconInt};
+use crate::messages::HubMessage;
 
 #[cw_serde]
 pub struct InstantiateMsg {
     pub xcall: Addr,
     pub icon_governance: String,
     pub protocols: ProtocolConfig,
+    // delay in seconds before queued governance actions can be executed
+    pub timelock: Option<u64>,
 }
 
 #[cw_serde]
@@ -16,12 +23,16 @@ pub enum ExecuteMsg {
     ProposeChange {
         protocol: String,
     },
-    RemoveProposal {},
+    // removes a single proposed removal, or all of them when no protocol is given
+    RemoveProposal {
+        protocol: Option<String>,
+    },
     ChangeProposer {
         proposer: Addr,
     },
     WhitelistAction {
         action: Vec<u8>,
+        expiry: Option<Expiration>,
     },
     RemoveAction {
         action: Vec<u8>,
@@ -31,6 +42,12 @@ pub enum ExecuteMsg {
         data: Vec<u8>,
         protocols: Option<Vec<String>>,
     },
+    ExecuteQueued {
+        id: u64,
+    },
+    VetoQueued {
+        id: u64,
+    },
 }
 
 #[cw_serde]
@@ -44,23 +61,102 @@ pub struct ProtocolConfig {
 pub enum QueryMsg {
     #[returns(bool)]
     VerifyProtocols { protocols: Vec<String> },
+    // falls back to the governance network's protocols for unconfigured routes
     #[returns(ProtocolConfig)]
-    GetProtocols {},
+    GetProtocols { nid: NetId },
+    #[returns(String)]
+    GetGovernance {},
+    #[returns(ConfigResponse)]
+    GetConfig {},
+    #[returns(Vec<String>)]
+    GetProposedRemoval {},
+    #[returns(Option<u32>)]
+    GetRemovalQuorum {},
+    #[returns(ProposerResponse)]
+    GetProposer {},
+    #[returns(Option<u64>)]
+    GetTimelock {},
+    #[returns(Vec<WhitelistedActionResponse>)]
+    GetWhitelistedActions {
+        start_after: Option<HexBinary>,
+        limit: Option<u32>,
+    },
+    #[returns(bool)]
+    IsActionWhitelisted { action: Vec<u8> },
+    // approvals collected so far for an action below the proposer threshold
+    #[returns(Option<PendingActionResponse>)]
+    GetPendingAction { action: Vec<u8> },
+    #[returns(Vec<QueuedActionResponse>)]
+    GetQueuedActions {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub xcall: String,
+    pub icon_governance: String,
+    pub timelock: Option<u64>,
+}
+
+#[cw_serde]
+pub struct ProposerResponse {
+    pub proposers: Vec<Addr>,
+    pub threshold: u32,
+    pub guardians: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct WhitelistedActionResponse {
+    // sha256 of the rlp encoded action
+    pub hash: HexBinary,
+    pub proposer: Addr,
+    pub expiry: Expiration,
 }
 
 #[cw_serde]
-pub struct MigrateMsg {}
+pub struct PendingActionResponse {
+    pub approvals: Vec<Addr>,
+    // fixed by the first approval, later approvals have to match it
+    pub expiry: Expiration,
+}
+
+#[cw_serde]
+pub enum GovernanceAction {
+    Execute(Execute),
+    Migrate(Migrate),
+    UpdateAdmin(UpdateAdmin),
+    BankSend(BankSend),
+    Batch(Batch),
+}
+
+// queued governance message, configuration changes are queued alongside actions
+#[cw_serde]
+pub struct QueuedActionResponse {
+    pub id: u64,
+    pub eta: Timestamp,
+    pub action: HubMessage,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {
+    // recovery path for when too many sources are compromised to reach the removal quorum
+    pub protocols: Option<ProtocolConfig>,
+}
 
 pub const CONFIGURE_PROTOCOLS: &str = "ConfigureProtocols";
 #[cw_serde]
 pub struct ConfigureProtocols {
     pub sources: Vec<String>,
     pub destinations: Vec<String>,
+    // route being configured, the governance network when not set
+    pub nid: Option<String>,
 }
 
 impl Encodable for ConfigureProtocols {
     fn rlp_append(&self, stream: &mut RlpStream) {
-        stream.begin_list(3);
+        stream.begin_list(if self.nid.is_some() { 4 } else { 3 });
         stream.append(&CONFIGURE_PROTOCOLS.to_string());
         stream.begin_list(self.sources.len());
         for protocol in self.sources.iter() {
@@ -71,6 +167,9 @@ impl Encodable for ConfigureProtocols {
         for protocol in self.destinations.iter() {
             stream.append(protocol);
         }
+        if let Some(nid) = &self.nid {
+            stream.append(nid);
+        }
     }
 }
 
@@ -78,9 +177,101 @@ impl Decodable for ConfigureProtocols {
     fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
         let rlp_sources = rlp.at(1)?;
         let rlp_destinations = rlp.at(2)?;
+        let nid = if rlp.item_count()? > 3 {
+            Some(rlp.val_at(3)?)
+        } else {
+            None
+        };
         Ok(Self {
             sources: rlp_sources.as_list()?,
             destinations: rlp_destinations.as_list()?,
+            nid,
+        })
+    }
+}
+
+pub const CONFIGURE_ROLES: &str = "ConfigureRoles";
+
+// replaces the proposer set, the number of proposers needed to whitelist an action and the
+// guardians, who may only propose protocol removals and veto queued actions
+#[cw_serde]
+pub struct ConfigureRoles {
+    pub proposers: Vec<String>,
+    pub threshold: u32,
+    pub guardians: Vec<String>,
+}
+
+impl Encodable for ConfigureRoles {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream.begin_list(4);
+        stream.append(&CONFIGURE_ROLES.to_string());
+        stream.begin_list(self.proposers.len());
+        for proposer in self.proposers.iter() {
+            stream.append(proposer);
+        }
+        stream.append(&IconInt(self.threshold.into()));
+        stream.begin_list(self.guardians.len());
+        for guardian in self.guardians.iter() {
+            stream.append(guardian);
+        }
+    }
+}
+
+impl Decodable for ConfigureRoles {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        Ok(Self {
+            proposers: rlp.at(1)?.as_list()?,
+            threshold: int_at(rlp, 2)?,
+            guardians: rlp.at(3)?.as_list()?,
+        })
+    }
+}
+
+pub const CONFIGURE_REMOVAL_QUORUM: &str = "ConfigureRemovalQuorum";
+
+// minimum number of current sources that must deliver a ConfigureProtocols dropping
+// proposed removals
+#[cw_serde]
+pub struct ConfigureRemovalQuorum {
+    pub quorum: u32,
+}
+
+impl Encodable for ConfigureRemovalQuorum {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream.begin_list(2);
+        stream.append(&CONFIGURE_REMOVAL_QUORUM.to_string());
+        stream.append(&IconInt(self.quorum.into()));
+    }
+}
+
+impl Decodable for ConfigureRemovalQuorum {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        Ok(Self {
+            quorum: int_at(rlp, 1)?,
+        })
+    }
+}
+
+pub const CONFIGURE_TIMELOCK: &str = "ConfigureTimelock";
+
+// delay in seconds before queued governance actions can be executed, zero disables the timelock
+#[cw_serde]
+pub struct ConfigureTimelock {
+    pub timelock: u64,
+}
+
+impl Encodable for ConfigureTimelock {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream.begin_list(2);
+        stream.append(&CONFIGURE_TIMELOCK.to_string());
+        stream.append(&IconInt(self.timelock.into()));
+    }
+}
+
+impl Decodable for ConfigureTimelock {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        Ok(Self {
+            timelock: int_at(rlp, 1)?,
         })
     }
 }
@@ -90,26 +281,78 @@ pub const EXECUTE: &str = "Execute";
 pub struct Execute {
     pub contract_addr: String,
     pub message: String, // Base64 encoded binary
+    // sent from the manager's balance, left out of the encoding when not set
+    pub funds: Option<Vec<Coin>>,
 }
 
 impl Encodable for Execute {
     fn rlp_append(&self, stream: &mut RlpStream) {
-        stream.begin_list(3);
+        stream.begin_list(if self.funds.is_some() { 4 } else { 3 });
         stream.append(&EXECUTE.to_string());
         stream.append(&self.contract_addr.to_string());
         stream.append(&self.message.to_string());
+        if let Some(funds) = &self.funds {
+            append_coins(stream, funds);
+        }
     }
 }
 
 impl Decodable for Execute {
     fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        let funds = if rlp.item_count()? > 3 {
+            Some(decode_coins(&rlp.at(3)?)?)
+        } else {
+            None
+        };
         Ok(Self {
             contract_addr: rlp.val_at(1)?,
             message: rlp.val_at(2)?,
+            funds,
         })
     }
 }
 
+pub const BANK_SEND: &str = "BankSend";
+#[cw_serde]
+pub struct BankSend {
+    pub to_address: String,
+    pub amount: Vec<Coin>,
+}
+
+impl Encodable for BankSend {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream.begin_list(3);
+        stream.append(&BANK_SEND.to_string());
+        stream.append(&self.to_address);
+        append_coins(stream, &self.amount);
+    }
+}
+
+impl Decodable for BankSend {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        Ok(Self {
+            to_address: rlp.val_at(1)?,
+            amount: decode_coins(&rlp.at(2)?)?,
+        })
+    }
+}
+
+// coins are encoded as a list of [denom, amount] pairs
+fn append_coins(stream: &mut RlpStream, coins: &[Coin]) {
+    stream.begin_list(coins.len());
+    for c in coins {
+        stream.begin_list(2);
+        stream.append(&c.denom);
+        stream.append(&IconInt(c.amount.u128()));
+    }
+}
+
+fn decode_coins(rlp: &Rlp) -> Result<Vec<Coin>, DecoderError> {
+    rlp.iter()
+        .map(|c| -> Result<Coin, DecoderError> { Ok(coin(int_at(&c, 1)?, c.val_at::<String>(0)?)) })
+        .collect()
+}
+
 pub const MIGRATE: &str = "Migrate";
 
 #[cw_serde]
@@ -121,10 +364,10 @@ pub struct Migrate {
 
 impl Encodable for Migrate {
     fn rlp_append(&self, stream: &mut RlpStream) {
-        stream.begin_list(3);
+        stream.begin_list(4);
         stream.append(&MIGRATE.to_string());
         stream.append(&self.contract_addr.to_string());
-        stream.append(&self.code_id);
+        stream.append(&IconInt(self.code_id.into()));
         stream.append(&self.message.to_string());
     }
 }
@@ -133,7 +376,7 @@ impl Decodable for Migrate {
     fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
         Ok(Self {
             contract_addr: rlp.val_at(1)?,
-            code_id: rlp.val_at(2)?,
+            code_id: int_at(rlp, 2)?,
             message: rlp.val_at(3)?,
         })
     }
@@ -163,3 +406,142 @@ impl Decodable for UpdateAdmin {
         })
     }
 }
+
+pub const BATCH: &str = "Batch";
+
+// a list of actions executed atomically and whitelisted as one unit
+#[cw_serde]
+pub struct Batch {
+    pub actions: Vec<GovernanceAction>,
+}
+
+impl Encodable for Batch {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        stream.begin_list(2);
+        stream.append(&BATCH.to_string());
+        stream.begin_list(self.actions.len());
+        for action in self.actions.iter() {
+            stream.append(action);
+        }
+    }
+}
+
+impl Decodable for Batch {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        let actions = rlp
+            .at(1)?
+            .iter()
+            .map(|action| {
+                let method: String = action.val_at(0)?;
+                if method == BATCH {
+                    return Err(DecoderError::Custom("Nested batch"));
+                }
+                GovernanceAction::decode(&action)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { actions })
+    }
+}
+
+impl Encodable for GovernanceAction {
+    fn rlp_append(&self, stream: &mut RlpStream) {
+        match self {
+            GovernanceAction::Execute(execute) => execute.rlp_append(stream),
+            GovernanceAction::Migrate(migrate) => migrate.rlp_append(stream),
+            GovernanceAction::UpdateAdmin(update_admin) => update_admin.rlp_append(stream),
+            GovernanceAction::BankSend(bank_send) => bank_send.rlp_append(stream),
+            GovernanceAction::Batch(batch) => batch.rlp_append(stream),
+        }
+    }
+}
+
+impl Decodable for GovernanceAction {
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        let method: String = rlp.val_at(0)?;
+        match method.as_str() {
+            EXECUTE => Ok(GovernanceAction::Execute(Execute::decode(rlp)?)),
+            MIGRATE => Ok(GovernanceAction::Migrate(Migrate::decode(rlp)?)),
+            UPDATE_ADMIN => Ok(GovernanceAction::UpdateAdmin(UpdateAdmin::decode(rlp)?)),
+            BANK_SEND => Ok(GovernanceAction::BankSend(BankSend::decode(rlp)?)),
+            BATCH => Ok(GovernanceAction::Batch(Batch::decode(rlp)?)),
+            _ => Err(DecoderError::Custom("Invalid governance action")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cw_ibc_rlp_lib::rlp::{decode, encode};
+
+    #[test]
+    fn migrate_encodes_every_field() {
+        let migrate = Migrate {
+            contract_addr: "hub".to_string(),
+            code_id: 42,
+            message: "e30=".to_string(),
+        };
+
+        let encoded = encode(&migrate);
+        assert_eq!(Rlp::new(&encoded).item_count().unwrap(), 4);
+        let decoded: Migrate = decode(&encoded).unwrap();
+        assert_eq!(decoded, migrate);
+    }
+
+    #[test]
+    fn batch_round_trip() {
+        let batch = Batch {
+            actions: vec![
+                GovernanceAction::Execute(Execute {
+                    contract_addr: "hub".to_string(),
+                    message: "e30=".to_string(),
+                    funds: None,
+                }),
+                GovernanceAction::UpdateAdmin(UpdateAdmin {
+                    contract_addr: "asset_manager".to_string(),
+                    admin: "admin".to_string(),
+                }),
+            ],
+        };
+
+        let encoded = encode(&batch);
+        let decoded: GovernanceAction = decode(&encoded).unwrap();
+        assert_eq!(decoded, GovernanceAction::Batch(batch));
+    }
+
+    #[test]
+    fn funded_execute_round_trip() {
+        let unfunded = Execute {
+            contract_addr: "hub".to_string(),
+            message: "e30=".to_string(),
+            funds: None,
+        };
+        let decoded: Execute = decode(&encode(&unfunded)).unwrap();
+        assert_eq!(decoded, unfunded);
+
+        let funded = Execute {
+            funds: Some(vec![coin(100, "uarch"), coin(5, "ibc/usdc")]),
+            ..unfunded
+        };
+        let decoded: Execute = decode(&encode(&funded)).unwrap();
+        assert_eq!(decoded, funded);
+
+        let bank_send = BankSend {
+            to_address: "fee_collector".to_string(),
+            amount: vec![coin(100, "uarch")],
+        };
+        let decoded: GovernanceAction = decode(&encode(&bank_send)).unwrap();
+        assert_eq!(decoded, GovernanceAction::BankSend(bank_send));
+    }
+
+    #[test]
+    fn nested_batch_is_rejected() {
+        let inner = GovernanceAction::Batch(Batch { actions: vec![] });
+        let batch = Batch {
+            actions: vec![inner],
+        };
+
+        let encoded = encode(&batch);
+        assert!(decode::<Batch>(&encoded).is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_slice, HexBinary};
+use cw_common::messages::HubMessage;
+use cw_ibc_rlp_lib::rlp::{decode, encode};
+
+#[cw_serde]
+struct Vector {
+    name: String,
+    hex: HexBinary,
+    message: HubMessage,
+}
+
+const FIXTURES: [(&str, &[u8]); 3] = [
+    (
+        "governance",
+        include_bytes!("../fixtures/rlp/governance.json"),
+    ),
+    ("spoke", include_bytes!("../fixtures/rlp/spoke.json")),
+    ("hub", include_bytes!("../fixtures/rlp/hub.json")),
+];
+
+fn vectors() -> Vec<(String, Vector)> {
+    FIXTURES
+        .iter()
+        .flat_map(|(file, fixture)| {
+            let vectors: Vec<Vector> = from_slice(fixture).unwrap();
+            vectors
+                .into_iter()
+                .map(move |vector| (format!("{}/{}", file, vector.name), vector))
+        })
+        .collect()
+}
+
+#[test]
+fn encode_matches_vectors() {
+    for (name, vector) in vectors() {
+        assert_eq!(
+            HexBinary::from(encode(&vector.message).to_vec()),
+            vector.hex,
+            "{}",
+            name
+        );
+    }
+}
+
+#[test]
+fn decode_matches_vectors() {
+    for (name, vector) in vectors() {
+        let decoded: HubMessage = decode(&vector.hex).unwrap();
+        assert_eq!(decoded, vector.message, "{}", name);
+    }
+}
+
+#[test]
+fn vectors_cover_every_message() {
+    let mut methods: Vec<String> = vectors()
+        .iter()
+        .map(|(_, vector)| vector.message.method().to_string())
+        .collect();
+    methods.sort();
+    methods.dedup();
+    assert_eq!(
+        methods,
+        vec![
+            "BankSend",
+            "Batch",
+            "ConfigureHub",
+            "ConfigureProtocols",
+            "ConfigureRemovalQuorum",
+            "ConfigureRoles",
+            "ConfigureTimelock",
+            "Deposit",
+            "DepositRevert",
+            "Execute",
+            "Migrate",
+            "UpdateAdmin",
+            "WithdrawNativeTo",
+            "WithdrawTo",
+            "xCrossTransfer",
+            "xCrossTransferRevert",
+        ]
+    );
+}
+
+// the unsigned and the ICON encoding only differ for zero and for values whose leading byte has
+// the high bit set, so the amounts of the main messages have to be covered in that range
+#[test]
+fn vectors_cover_high_bit_amounts() {
+    let high_bit =
+        |value: u128| value != 0 && value.to_be_bytes()[value.leading_zeros() as usize / 8] >= 0x80;
+    let mut methods: Vec<String> = vectors()
+        .iter()
+        .filter(|(_, vector)| match &vector.message {
+            HubMessage::Deposit(msg) => high_bit(msg.amount),
+            HubMessage::DepositRevert(msg) => high_bit(msg.amount),
+            HubMessage::WithdrawTo(msg) => high_bit(msg.amount),
+            HubMessage::CrossTransfer(msg) => high_bit(msg.value),
+            _ => false,
+        })
+        .map(|(_, vector)| vector.message.method().to_string())
+        .collect();
+    methods.sort();
+    methods.dedup();
+    assert_eq!(
+        methods,
+        vec!["Deposit", "DepositRevert", "WithdrawTo", "xCrossTransfer"]
+    );
+}
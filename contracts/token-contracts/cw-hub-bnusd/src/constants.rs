@@ -1,9 +1,12 @@
 use cosmwasm_std::Uint128;
 
-pub const X_CROSS_TRANSFER: &str = "xCrossTransfer";
-pub const X_CROSS_TRANSFER_REVERT: &str = "xCrossTransferRevert";
+pub use cw_common::data_types::{X_CROSS_TRANSFER, X_CROSS_TRANSFER_REVERT};
 pub const TOKEN_NAME: &str = "Balanced Dollar";
 pub const TOKEN_SYMBOL: &str = "bnUSD";
 pub const TOKEN_DECIMALS: u8 = 18;
 pub const TOKEN_TOTAL_SUPPLY: Uint128 = Uint128::zero();
 pub const PROTOCOL: &str = "btp";
+
+//Reply messages identifiers for the injective cw20 adapter
+pub const ADAPTER_RECEIVE_REPLY_ID: u64 = 1;
+pub const ADAPTER_REDEEM_REPLY_ID: u64 = 2;
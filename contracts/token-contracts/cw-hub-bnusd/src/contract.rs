@@ -5,23 +5,30 @@ use crate::constants::{
     X_CROSS_TRANSFER_REVERT,
 };
 use crate::error::ContractError;
+use crate::marketing;
 use crate::state::{
-    DESTINATION_TOKEN_ADDRESS, DESTINATION_TOKEN_NET, NID, OWNER, X_CALL, X_CALL_MANAGER,
-    X_CALL_NETWORK_ADDRESS,
+    BLOCKLIST, CROSS_TRANSFER_NONCE, DESTINATION_TOKEN_ADDRESS, DESTINATION_TOKEN_NET,
+    FROZEN_REFUNDS, NID, OWNER, PAUSED, RECIPIENT_FORMATS, TOKEN_FACTORY, X_CALL, X_CALL_MANAGER,
+    X_CALL_NETWORK_ADDRESS, X_CROSS_TRANSFER_METHOD, X_CROSS_TRANSFER_REVERT_METHOD,
+};
+use crate::token_factory::TokenFactory;
+use cw_common::decimals::{from_hub_amount, to_hub_amount};
+use cw_common::events::{recipient_format_configured_event, xcall_configured_event};
+use cw_common::network_address::{
+    recipient_format, validate_recipient, AddressFormat, IconAddressValidation,
 };
-use cw_common::helpers::verify_protocol;
-use cw_common::network_address::IconAddressValidation;
 
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Empty, Env, MessageInfo, QueryRequest, Response,
-    StdResult, WasmQuery,
+    ensure, to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage,
+    Uint128,
 };
 
 use cw2::set_contract_version;
 use cw_common::hub_token_msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
-use cw_common::x_call_msg::{GetNetworkAddress, XCallMsg};
+use cw_common::x_call_msg::XCallMsg;
+use cw_common::xcall_client::{XCallClient, XCallManagerClient};
 
 use cw20_base::allowances::{
     execute_burn_from, execute_decrease_allowance, execute_increase_allowance, execute_send_from,
@@ -33,25 +40,34 @@ use cw20_base::contract::{
 };
 use cw20_base::contract::{query_balance, query_minter, query_token_info};
 use cw20_base::enumerable::{query_all_accounts, query_owner_allowances, query_spender_allowances};
+use cw20_base::msg::InstantiateMsg as Cw20InstantiateMsg;
 use cw20_base::state::{MinterData, TokenInfo, TOKEN_INFO};
 use cw_common::network_address::NetworkAddress;
 
 use cw_ibc_rlp_lib::rlp::Rlp;
 use debug_print::debug_println;
 
+#[cfg(feature = "injective")]
+use crate::constants::{ADAPTER_RECEIVE_REPLY_ID, ADAPTER_REDEEM_REPLY_ID};
 #[cfg(feature = "injective")]
 use crate::cw20_adapter::CW20Adapter;
 #[cfg(feature = "injective")]
-use crate::state::CW20_ADAPTER;
+use crate::state::{CW20_ADAPTER, PENDING_ADAPTER_RECEIVE, PENDING_ADAPTER_REDEEM};
+#[cfg(feature = "injective")]
+use cosmwasm_std::{Reply, SubMsgResult};
+#[cfg(feature = "injective")]
+use cw20_base::state::BALANCES;
+#[cfg(feature = "injective")]
+use cw_common::hub_token_msg::AdapterResponse;
 
-use cw_common::data_types::{CrossTransfer, CrossTransferRevert};
+use cw_common::data_types::{ConfigureHub, CrossTransfer, CrossTransferRevert, CONFIGURE_HUB};
 
 const CONTRACT_NAME: &str = "crates.io:cw-hub-bnusd";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
@@ -67,27 +83,72 @@ pub fn instantiate(
 
     OWNER.save(deps.storage, &info.sender)?;
 
+    if let Some(marketing) = msg.marketing {
+        marketing::instantiate_marketing(&mut deps, marketing)?;
+    }
+
     let hub_network_address =
         NetworkAddress::from_str(&msg.hub_address).map_err(ContractError::Std)?;
 
+    let name = msg.name.unwrap_or_else(|| TOKEN_NAME.to_string());
+    let symbol = msg.symbol.unwrap_or_else(|| TOKEN_SYMBOL.to_string());
+    let decimals = msg.decimals.unwrap_or(TOKEN_DECIMALS);
+    // reuse cw20-base's checks on name, symbol and decimals
+    Cw20InstantiateMsg {
+        name: name.clone(),
+        symbol: symbol.clone(),
+        decimals,
+        initial_balances: vec![],
+        mint: None,
+        marketing: None,
+    }
+    .validate()?;
+
+    let cross_transfer_method = msg
+        .cross_transfer_method
+        .unwrap_or_else(|| X_CROSS_TRANSFER.to_string());
+    let cross_transfer_revert_method = msg
+        .cross_transfer_revert_method
+        .unwrap_or_else(|| X_CROSS_TRANSFER_REVERT.to_string());
+    X_CROSS_TRANSFER_METHOD.save(deps.storage, &cross_transfer_method)?;
+    X_CROSS_TRANSFER_REVERT_METHOD.save(deps.storage, &cross_transfer_revert_method)?;
+
+    let token_factory = msg.token_factory.map(|config| {
+        TokenFactory::new(
+            env.contract.address.clone(),
+            config.subdenom,
+            config.proto_package,
+        )
+    });
+    if let Some(token_factory) = &token_factory {
+        TOKEN_FACTORY.save(deps.storage, token_factory)?;
+    }
+
     let token_info = TokenInfo {
-        name: TOKEN_NAME.to_string(),
-        symbol: TOKEN_SYMBOL.to_string(),
-        decimals: TOKEN_DECIMALS,
+        name,
+        symbol,
+        decimals,
         total_supply: TOKEN_TOTAL_SUPPLY,
         mint: Some(MinterData {
             minter: x_call_addr.clone(),
             cap: None,
         }),
     };
-    setup_function(
+    let res = setup_function(
         deps,
         env,
         x_call_addr,
         hub_network_address,
         token_info,
         msg.manager,
-    )
+    )?;
+
+    Ok(match token_factory {
+        Some(token_factory) => res
+            .add_message(token_factory.create_denom())
+            .add_attribute("denom", token_factory.denom()),
+        None => res,
+    })
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -108,33 +169,50 @@ pub fn execute(
             data,
             protocols,
         } => {
-            let xcall_manger = X_CALL_MANAGER.load(deps.storage)?;
-            let res = verify_protocol(&deps, xcall_manger, protocols);
-
-            if res.is_err() {
-                return Err(ContractError::Unauthorized);
-            }
+            let xcall_manager = XCallManagerClient::new(X_CALL_MANAGER.load(deps.storage)?);
+            ensure!(
+                xcall_manager.verify_protocols(&deps.querier, protocols)?,
+                ContractError::Unauthorized
+            );
 
             execute::handle_call_message(deps, env, info, from, data)
         }
         ExecuteMsg::CrossTransfer { to, amount, data } => {
+            execute::ensure_transferable(&deps, &[info.sender.as_str()])?;
+            execute::ensure_valid_recipient(deps.as_ref(), &to)?;
             execute::cross_transfer(deps, env, info, to, amount, data)
         }
+        ExecuteMsg::CrossTransferFrom {
+            owner,
+            to,
+            amount,
+            data,
+        } => {
+            execute::ensure_transferable(&deps, &[info.sender.as_str(), &owner])?;
+            execute::ensure_valid_recipient(deps.as_ref(), &to)?;
+            execute::cross_transfer_from(deps, env, info, owner, to, amount, data)
+        }
         ExecuteMsg::Transfer { recipient, amount } => {
+            execute::ensure_transferable(&deps, &[info.sender.as_str(), &recipient])?;
             execute_transfer(deps, env, info, recipient, amount)
                 .map_err(ContractError::Cw20BaseError)
         }
         ExecuteMsg::Burn { amount } => {
+            execute::ensure_transferable(&deps, &[info.sender.as_str()])?;
             execute_burn(deps, env, info, amount).map_err(ContractError::Cw20BaseError)
         }
         ExecuteMsg::Send {
             contract,
             amount,
             msg,
-        } => execute_send(deps, env, info, contract, amount, msg)
-            .map_err(ContractError::Cw20BaseError),
+        } => {
+            execute::ensure_transferable(&deps, &[info.sender.as_str(), &contract])?;
+            execute_send(deps, env, info, contract, amount, msg)
+                .map_err(ContractError::Cw20BaseError)
+        }
         ExecuteMsg::Mint { recipient, amount } => {
-            execute_mint(deps, env, info, recipient, amount).map_err(ContractError::Cw20BaseError)
+            execute::ensure_not_blocked(&deps, &recipient)?;
+            execute::mint(deps, env, info, recipient, amount)
         }
         ExecuteMsg::IncreaseAllowance {
             spender,
@@ -152,9 +230,13 @@ pub fn execute(
             owner,
             recipient,
             amount,
-        } => execute_transfer_from(deps, env, info, owner, recipient, amount)
-            .map_err(ContractError::Cw20BaseError),
+        } => {
+            execute::ensure_transferable(&deps, &[info.sender.as_str(), &owner, &recipient])?;
+            execute_transfer_from(deps, env, info, owner, recipient, amount)
+                .map_err(ContractError::Cw20BaseError)
+        }
         ExecuteMsg::BurnFrom { owner, amount } => {
+            execute::ensure_transferable(&deps, &[info.sender.as_str(), &owner])?;
             execute_burn_from(deps, env, info, owner, amount).map_err(ContractError::Cw20BaseError)
         }
         ExecuteMsg::SendFrom {
@@ -162,20 +244,31 @@ pub fn execute(
             contract,
             amount,
             msg,
-        } => execute_send_from(deps, env, info, owner, contract, amount, msg)
-            .map_err(ContractError::Cw20BaseError),
+        } => {
+            execute::ensure_transferable(&deps, &[info.sender.as_str(), &owner, &contract])?;
+            execute_send_from(deps, env, info, owner, contract, amount, msg)
+                .map_err(ContractError::Cw20BaseError)
+        }
         ExecuteMsg::UpdateMinter { new_minter } => {
             execute_update_minter(deps, env, info, new_minter).map_err(ContractError::Cw20BaseError)
         }
         #[cfg(feature = "injective")]
         ExecuteMsg::SetAdapter { registry_contract } => {
-            let owner = OWNER.load(deps.storage)?;
-            assert!(owner == info.sender);
-            let registry = deps.api.addr_validate(&registry_contract)?;
-            let adapter = CW20Adapter::new(env.contract.address, registry);
-            CW20_ADAPTER.save(deps.storage, &adapter)?;
-            Ok(Response::new())
+            execute::set_adapter(deps, env, info, registry_contract)
+        }
+        ExecuteMsg::Pause {} => execute::set_paused(deps, info, true),
+        ExecuteMsg::Unpause {} => execute::set_paused(deps, info, false),
+        ExecuteMsg::Block { address } => execute::set_blocked(deps, info, address, true),
+        ExecuteMsg::Unblock { address } => execute::set_blocked(deps, info, address, false),
+        ExecuteMsg::ConfigureRecipientFormat { nid, format } => {
+            execute::configure_recipient_format(deps, info, nid, format)
         }
+        ExecuteMsg::UpdateMarketing {
+            project,
+            description,
+            marketing,
+        } => marketing::update_marketing(deps, info, project, description, marketing),
+        ExecuteMsg::UploadLogo(logo) => marketing::upload_logo(deps, info, logo),
     }
 }
 
@@ -208,9 +301,94 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         }
         QueryMsg::MarketingInfo {} => to_binary(&query_marketing_info(deps)?),
         QueryMsg::DownloadLogo {} => to_binary(&query_download_logo(deps)?),
+        QueryMsg::IsPaused {} => to_binary(&PAUSED.may_load(deps.storage)?.unwrap_or_default()),
+        QueryMsg::IsBlocked { address } => to_binary(&is_blocked(deps, &address)?),
+        QueryMsg::FrozenRefund { address } => to_binary(&Uint128::from(
+            FROZEN_REFUNDS
+                .may_load(deps.storage, address)?
+                .unwrap_or_default(),
+        )),
+        QueryMsg::GetRecipientFormat { nid } => {
+            let configured = RECIPIENT_FORMATS.may_load(deps.storage, nid.clone())?;
+            to_binary(&recipient_format(&nid, configured))
+        }
+        QueryMsg::Denom {} => to_binary(
+            &TOKEN_FACTORY
+                .may_load(deps.storage)?
+                .map(|token_factory| token_factory.denom()),
+        ),
+        #[cfg(feature = "injective")]
+        QueryMsg::GetAdapter {} => {
+            let adapter = CW20_ADAPTER.load(deps.storage)?;
+            to_binary(&AdapterResponse {
+                adapter_contract: adapter.adapter_contract().clone(),
+                denom: adapter.denom(),
+            })
+        }
+    }
+}
+
+#[cfg(feature = "injective")]
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        ADAPTER_RECEIVE_REPLY_ID => {
+            let (recipient, amount) = PENDING_ADAPTER_RECEIVE.load(deps.storage)?;
+            PENDING_ADAPTER_RECEIVE.remove(deps.storage);
+            match msg.result {
+                SubMsgResult::Ok(_) => Ok(Response::new()),
+                // the tokens minted to the adapter are handed to the recipient as cw20 instead
+                SubMsgResult::Err(error) => {
+                    credit_from_adapter(deps, &recipient, amount)?;
+                    Ok(Response::new()
+                        .add_attribute("method", "adapter_receive_failed")
+                        .add_attribute("recipient", recipient)
+                        .add_attribute("amount", amount)
+                        .add_attribute("error", error))
+                }
+            }
+        }
+        ADAPTER_REDEEM_REPLY_ID => {
+            let (sender, amount) = PENDING_ADAPTER_REDEEM.load(deps.storage)?;
+            PENDING_ADAPTER_REDEEM.remove(deps.storage);
+            match msg.result {
+                SubMsgResult::Ok(_) => Ok(Response::new()),
+                // the sender's cw20 is released from the adapter's backing so the burn that
+                // follows still succeeds, the tokenfactory funds stay locked in this contract
+                SubMsgResult::Err(error) => {
+                    credit_from_adapter(deps, &sender, amount)?;
+                    Ok(Response::new()
+                        .add_attribute("method", "adapter_redeem_failed")
+                        .add_attribute("sender", sender)
+                        .add_attribute("amount", amount)
+                        .add_attribute("error", error))
+                }
+            }
+        }
+        id => Err(ContractError::UnknownReplyId { id }),
     }
 }
 
+#[cfg(feature = "injective")]
+fn credit_from_adapter(deps: DepsMut, account: &Addr, amount: Uint128) -> StdResult<()> {
+    let adapter = CW20_ADAPTER.load(deps.storage)?;
+    BALANCES.update(
+        deps.storage,
+        adapter.adapter_contract(),
+        |balance: Option<Uint128>| -> StdResult<_> {
+            Ok(balance.unwrap_or_default().checked_sub(amount)?)
+        },
+    )?;
+    BALANCES.update(
+        deps.storage,
+        account,
+        |balance: Option<Uint128>| -> StdResult<_> {
+            Ok(balance.unwrap_or_default().checked_add(amount)?)
+        },
+    )?;
+    Ok(())
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)
@@ -224,12 +402,18 @@ mod execute {
 
     #[cfg(feature = "injective")]
     use crate::cw20_adapter::CW20Adapter;
-    use crate::events::{emit_cross_transfer_event, emit_cross_transfer_revert_event};
+    #[cfg(feature = "injective")]
+    use crate::events::adapter_configured_event;
+    use crate::events::{
+        blocklist_updated_event, cross_transfer_event, cross_transfer_revert_event,
+        hub_configured_event, paused_updated_event,
+    };
     use bytes::BytesMut;
-    use cosmwasm_std::{ensure, to_binary, Addr, CosmosMsg, SubMsg};
-    use cw_common::{helpers::get_protocols, network_address::NetId};
+    use cosmwasm_std::{ensure, to_binary, Addr, Coin, CosmosMsg, Storage, SubMsg};
+    use cw_common::network_address::NetId;
     use cw_ibc_rlp_lib::rlp::{decode, encode};
     use debug_print::debug_println;
+    use sha2::{Digest, Sha256};
 
     use super::*;
 
@@ -263,6 +447,164 @@ mod execute {
         )
     }
 
+    pub fn set_paused(
+        deps: DepsMut,
+        info: MessageInfo,
+        paused: bool,
+    ) -> Result<Response, ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if owner != info.sender {
+            return Err(ContractError::Unauthorized);
+        }
+
+        PAUSED.save(deps.storage, &paused)?;
+        Ok(Response::new()
+            .add_attribute("method", "set_paused")
+            .add_attribute("paused", paused.to_string())
+            .add_event(paused_updated_event(paused)))
+    }
+
+    pub fn set_blocked(
+        deps: DepsMut,
+        info: MessageInfo,
+        address: String,
+        blocked: bool,
+    ) -> Result<Response, ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if owner != info.sender {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let address = deps.api.addr_validate(&address)?;
+        let mut res = Response::new();
+        let mut released = 0;
+        if blocked {
+            BLOCKLIST.save(deps.storage, address.to_string(), &true)?;
+        } else {
+            BLOCKLIST.remove(deps.storage, address.to_string());
+            // refunds held while the address was blocked are released with it
+            if let Some(refund) = FROZEN_REFUNDS.may_load(deps.storage, address.to_string())? {
+                FROZEN_REFUNDS.remove(deps.storage, address.to_string());
+                let token_factory = TOKEN_FACTORY.load(deps.storage)?;
+                res = res
+                    .add_message(token_factory.transfer(&address, refund))
+                    .add_attribute("refund", refund.to_string());
+                released = refund;
+            }
+        }
+
+        Ok(res
+            .add_attribute("method", "set_blocked")
+            .add_attribute("address", address.clone())
+            .add_attribute("blocked", blocked.to_string())
+            .add_event(blocklist_updated_event(&address, blocked, released)))
+    }
+
+    #[cfg(feature = "injective")]
+    pub fn set_adapter(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        registry_contract: String,
+    ) -> Result<Response, ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if owner != info.sender {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let registry = deps.api.addr_validate(&registry_contract)?;
+        let adapter = CW20Adapter::new(env.contract.address, registry);
+        CW20_ADAPTER.save(deps.storage, &adapter)?;
+
+        Ok(Response::new()
+            .add_message(adapter.register(info.funds))
+            .add_attribute("method", "set_adapter")
+            .add_attribute("adapter", adapter.adapter_contract())
+            .add_attribute("denom", adapter.denom())
+            .add_event(adapter_configured_event(
+                adapter.adapter_contract(),
+                &adapter.denom(),
+            )))
+    }
+
+    // the minter's mints are paid in the denom when balances are held by the tokenfactory
+    pub fn mint(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        recipient: String,
+        amount: Uint128,
+    ) -> Result<Response, ContractError> {
+        let token_factory = match TOKEN_FACTORY.may_load(deps.storage)? {
+            Some(token_factory) => token_factory,
+            None => {
+                return execute_mint(deps, env, info, recipient, amount)
+                    .map_err(ContractError::Cw20BaseError)
+            }
+        };
+        let minter = TOKEN_INFO.load(deps.storage)?.mint.map(|mint| mint.minter);
+        ensure!(minter == Some(info.sender), ContractError::Unauthorized);
+        ensure!(!amount.is_zero(), ContractError::InvalidAmount);
+        let recipient = deps.api.addr_validate(&recipient)?;
+
+        Ok(Response::new()
+            .add_message(token_factory.mint(amount.u128()))
+            .add_message(token_factory.transfer(&recipient, amount.u128()))
+            .add_attribute("method", "mint")
+            .add_attribute("to", recipient)
+            .add_attribute("amount", amount))
+    }
+
+    // transfers are rejected while paused or when any of the involved accounts is blocked
+    pub fn ensure_transferable(deps: &DepsMut, accounts: &[&str]) -> Result<(), ContractError> {
+        let paused = PAUSED.may_load(deps.storage)?.unwrap_or_default();
+        ensure!(!paused, ContractError::Paused);
+
+        accounts
+            .iter()
+            .try_for_each(|account| ensure_not_blocked(deps, account))
+    }
+
+    pub fn configure_recipient_format(
+        deps: DepsMut,
+        info: MessageInfo,
+        nid: String,
+        format: Option<AddressFormat>,
+    ) -> Result<Response, ContractError> {
+        let owner = OWNER.load(deps.storage)?;
+        if owner != info.sender {
+            return Err(ContractError::Unauthorized);
+        }
+
+        match &format {
+            Some(format) => RECIPIENT_FORMATS.save(deps.storage, nid.clone(), format)?,
+            None => RECIPIENT_FORMATS.remove(deps.storage, nid.clone()),
+        }
+
+        Ok(Response::new()
+            .add_attribute("method", "configure_recipient_format")
+            .add_event(recipient_format_configured_event(&nid, format.as_ref())))
+    }
+
+    // tokens sent to a malformed address on the destination chain can't be recovered
+    pub fn ensure_valid_recipient(deps: Deps, to: &NetworkAddress) -> Result<(), ContractError> {
+        let format = RECIPIENT_FORMATS.may_load(deps.storage, to.nid().to_string())?;
+        ensure!(
+            validate_recipient(to, format),
+            ContractError::InvalidToAddress
+        );
+        Ok(())
+    }
+
+    pub fn ensure_not_blocked(deps: &DepsMut, account: &str) -> Result<(), ContractError> {
+        if is_blocked(deps.as_ref(), account)? {
+            return Err(ContractError::AddressBlocked {
+                address: account.to_string(),
+            });
+        }
+        Ok(())
+    }
+
     pub fn handle_call_message(
         deps: DepsMut,
         env: Env,
@@ -275,8 +617,9 @@ mod execute {
             return Err(ContractError::OnlyCallService);
         }
 
+        // payloads come from other chains, malformed ones are rejected rather than aborting
         let rlp: Rlp = Rlp::new(&data);
-        let data_list: Vec<BytesMut> = rlp.as_list().unwrap();
+        let data_list: Vec<BytesMut> = rlp.as_list().map_err(|_| ContractError::InvalidData)?;
 
         if data_list.len() <= 2 {
             return Err(ContractError::InvalidData);
@@ -284,26 +627,85 @@ mod execute {
         debug_println!("datalist {:?}", data_list);
 
         let data_list = &data_list[0].to_vec();
-        let method = from_utf8(data_list).unwrap();
+        let method = from_utf8(data_list).map_err(|_| ContractError::InvalidData)?;
         debug_println!("method {:?}", method);
-        let mut res = match method {
-            X_CROSS_TRANSFER => {
-                let cross_transfer_data: CrossTransfer = decode(&data).unwrap();
-                x_cross_transfer(deps, env, info, from, cross_transfer_data)
-            }
-            X_CROSS_TRANSFER_REVERT => {
-                let cross_transfer_revert_data: CrossTransferRevert = decode(&data).unwrap();
-                x_cross_transfer_revert(deps, env, info, from, cross_transfer_revert_data)
-            }
-            _ => {
-                return Err(ContractError::InvalidMethod);
-            }
+        let mut res = if method == cross_transfer_method(deps.storage)? {
+            let cross_transfer_data: CrossTransfer =
+                decode(&data).map_err(|_| ContractError::InvalidData)?;
+            x_cross_transfer(deps, env, info, from, cross_transfer_data)
+        } else if method == cross_transfer_revert_method(deps.storage)? {
+            let cross_transfer_revert_data: CrossTransferRevert =
+                decode(&data).map_err(|_| ContractError::InvalidData)?;
+            x_cross_transfer_revert(deps, env, info, from, cross_transfer_revert_data)
+        } else if method == CONFIGURE_HUB {
+            let configure_hub_data: ConfigureHub =
+                decode(&data).map_err(|_| ContractError::InvalidData)?;
+            configure_hub(deps, env, from, configure_hub_data)
+        } else {
+            return Err(ContractError::InvalidMethod);
         };
         res = res.map(|res| res.add_attribute("action", "handle_call_message"));
 
         res
     }
 
+    // reconfigures the hub token on behalf of ICON governance, protocols are already verified
+    // against the xcall manager by the caller
+    pub fn configure_hub(
+        deps: DepsMut,
+        env: Env,
+        from: NetworkAddress,
+        configure_hub: ConfigureHub,
+    ) -> Result<Response, ContractError> {
+        let xcall_manager = X_CALL_MANAGER.load(deps.storage)?;
+        let governance =
+            XCallManagerClient::new(xcall_manager.clone()).governance(&deps.querier)?;
+        if from.to_string() != governance {
+            return Err(ContractError::OnlyGovernance);
+        }
+
+        let x_call = match configure_hub.x_call.is_empty() {
+            true => X_CALL.load(deps.storage)?,
+            false => deps.api.addr_validate(&configure_hub.x_call)?,
+        };
+        let hub_network_address = match configure_hub.hub_address.is_empty() {
+            true => NetworkAddress::new(
+                &DESTINATION_TOKEN_NET.load(deps.storage)?.to_string(),
+                DESTINATION_TOKEN_ADDRESS.load(deps.storage)?.as_str(),
+            ),
+            false => NetworkAddress::from_str(&configure_hub.hub_address)?,
+        };
+        let manager = match configure_hub.manager.is_empty() {
+            true => xcall_manager,
+            false => deps.api.addr_validate(&configure_hub.manager)?,
+        };
+
+        let mut token_info = TOKEN_INFO.load(deps.storage)?;
+        token_info.mint = Some(MinterData {
+            minter: x_call.clone(),
+            cap: None,
+        });
+        let res = setup_function(
+            deps,
+            env,
+            x_call.clone(),
+            hub_network_address.clone(),
+            token_info,
+            manager.clone(),
+        )?;
+
+        Ok(res
+            .add_attribute("method", "configure_hub")
+            .add_attribute("x_call", x_call.clone())
+            .add_attribute("hub_address", hub_network_address.to_string())
+            .add_attribute("manager", manager.clone())
+            .add_event(hub_configured_event(
+                &x_call,
+                &hub_network_address,
+                &manager,
+            )))
+    }
+
     pub fn cross_transfer(
         deps: DepsMut,
         env: Env,
@@ -316,57 +718,53 @@ mod execute {
 
         let info_copy = info.clone();
         let nid = NID.load(deps.storage)?;
-        let hub_net: NetId = DESTINATION_TOKEN_NET.load(deps.storage)?;
-        let hub_address: Addr = DESTINATION_TOKEN_ADDRESS.load(deps.storage)?;
-        let sender = &info.sender;
 
+        let token_factory = TOKEN_FACTORY.may_load(deps.storage)?;
+        if let Some(token_factory) = &token_factory {
+            // the bridged amount is paid in the denom, remaining funds cover the xcall fee
+            let (denom_funds, other) = token_factory.split_funds(&info);
+            ensure!(denom_funds == amount, ContractError::InvalidAmount);
+            info.funds = other;
+        }
         #[cfg(feature = "injective")]
-        {
+        if token_factory.is_none() {
             let adapter: CW20Adapter = CW20_ADAPTER.load(deps.storage)?;
             let (_adpater_funds, other) = adapter.split_adapter_funds(&info);
             info.funds = other;
         }
 
         let from = NetworkAddress::new(&nid.to_string(), info.sender.as_ref());
-
-        let call_data = CrossTransfer {
-            method: X_CROSS_TRANSFER.to_string(),
-            from: from.clone(),
-            to: to.clone(),
-            value: amount,
-            data: data.clone(),
-        };
-        let rollback_data = CrossTransferRevert {
-            method: X_CROSS_TRANSFER_REVERT.to_string(),
-            from: sender.clone(),
-            value: amount,
-        };
-
-        let hub_token_address = NetworkAddress::new(&hub_net.to_string(), hub_address.as_ref());
-        let cfg = get_protocols(&deps.as_ref(), X_CALL_MANAGER.load(deps.storage)?).unwrap();
-        let call_message = XCallMsg::SendCallMessage {
-            to: hub_token_address,
-            data: encode(&call_data).to_vec(),
-            rollback: Some(encode(&rollback_data).to_vec()),
-            sources: Some(cfg.sources),
-            destinations: Some(cfg.destinations),
-        };
-
-        let wasm_execute_message: CosmosMsg = CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
-            contract_addr: X_CALL.load(deps.storage).unwrap().to_string(),
-            msg: to_binary(&call_message)?,
-            funds: info.funds.clone(),
-        });
-
-        let sub_message = SubMsg::new(wasm_execute_message);
+        let nonce = next_cross_transfer_nonce(deps.storage)?;
+        let sub_message = cross_transfer_message(
+            &deps,
+            from.clone(),
+            info.sender.clone(),
+            to.clone(),
+            amount,
+            data.clone(),
+            nonce,
+            info.funds.clone(),
+        )?;
         debug_println!("this is {:?}", info.sender);
 
         debug_println!("burn from {:?}", sub_message);
-        let event = emit_cross_transfer_event("CrossTransfer".to_string(), from, to, amount, data);
+        let event = cross_transfer_event("CrossTransfer".to_string(), from, to, amount, data)
+            .add_attribute("nonce", nonce.to_string());
+        if let Some(token_factory) = token_factory {
+            return Ok(Response::new()
+                .add_message(token_factory.burn(amount))
+                .add_submessage(sub_message)
+                .add_attribute("method", "cross_transfer")
+                .add_event(event));
+        }
         #[cfg(feature = "injective")]
         {
             let adapter = CW20_ADAPTER.load(deps.storage)?;
             let tf_tokens = adapter.get_adapter_fund(&info_copy);
+            if tf_tokens > 0 {
+                PENDING_ADAPTER_REDEEM
+                    .save(deps.storage, &(info.sender.clone(), tf_tokens.into()))?;
+            }
             let mut response = cw20_base::allowances::execute_increase_allowance(
                 deps,
                 env.clone(),
@@ -399,6 +797,108 @@ mod execute {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub fn cross_transfer_from(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        owner: String,
+        to: NetworkAddress,
+        amount: u128,
+        data: Vec<u8>,
+    ) -> Result<Response, ContractError> {
+        ensure!(amount > 0, ContractError::InvalidAmount);
+        // bank denoms have no allowances to spend from
+        ensure!(
+            TOKEN_FACTORY.may_load(deps.storage)?.is_none(),
+            ContractError::TokenFactoryUnsupported
+        );
+
+        let owner_addr = deps.api.addr_validate(&owner)?;
+        let nid = NID.load(deps.storage)?;
+        let from = NetworkAddress::new(&nid.to_string(), owner_addr.as_ref());
+
+        let nonce = next_cross_transfer_nonce(deps.storage)?;
+        let sub_message = cross_transfer_message(
+            &deps,
+            from.clone(),
+            owner_addr,
+            to.clone(),
+            amount,
+            data.clone(),
+            nonce,
+            info.funds.clone(),
+        )?;
+        let event = cross_transfer_event("CrossTransfer".to_string(), from, to, amount, data)
+            .add_attribute("nonce", nonce.to_string());
+
+        let result = execute_burn_from(deps, env, info, owner, amount.into())
+            .map_err(ContractError::Cw20BaseError)?;
+
+        Ok(result
+            .add_submessage(sub_message)
+            .add_attribute("method", "cross_transfer_from")
+            .add_event(event))
+    }
+
+    fn next_cross_transfer_nonce(storage: &mut dyn Storage) -> Result<u128, ContractError> {
+        let nonce = CROSS_TRANSFER_NONCE.may_load(storage)?.unwrap_or_default() + 1;
+        CROSS_TRANSFER_NONCE.save(storage, &nonce)?;
+        Ok(nonce)
+    }
+
+    // builds the xcall message bridging amount to the hub, refunding revert_to on rollback
+    #[allow(clippy::too_many_arguments)]
+    fn cross_transfer_message(
+        deps: &DepsMut,
+        from: NetworkAddress,
+        revert_to: Addr,
+        to: NetworkAddress,
+        amount: u128,
+        data: Vec<u8>,
+        nonce: u128,
+        funds: Vec<Coin>,
+    ) -> Result<SubMsg, ContractError> {
+        let hub_net: NetId = DESTINATION_TOKEN_NET.load(deps.storage)?;
+        let hub_address: Addr = DESTINATION_TOKEN_ADDRESS.load(deps.storage)?;
+
+        let rollback_data = CrossTransferRevert {
+            method: cross_transfer_revert_method(deps.storage)?,
+            from: revert_to,
+            value: amount,
+            to: Some(to.clone()),
+            data_hash: Sha256::digest(&data).to_vec(),
+            nonce,
+        };
+        let decimals = TOKEN_INFO.load(deps.storage)?.decimals;
+        let call_data = CrossTransfer {
+            method: cross_transfer_method(deps.storage)?,
+            from,
+            to,
+            value: to_hub_amount(amount, decimals)?,
+            data,
+        };
+
+        let hub_token_address = NetworkAddress::new(&hub_net.to_string(), hub_address.as_ref());
+        let cfg = XCallManagerClient::new(X_CALL_MANAGER.load(deps.storage)?)
+            .protocols(&deps.querier, hub_net.clone())?;
+        let call_message = XCallMsg::SendCallMessage {
+            to: hub_token_address,
+            data: encode(&call_data).to_vec(),
+            rollback: Some(encode(&rollback_data).to_vec()),
+            sources: Some(cfg.sources),
+            destinations: Some(cfg.destinations),
+        };
+
+        let wasm_execute_message: CosmosMsg = CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
+            contract_addr: X_CALL.load(deps.storage)?.to_string(),
+            msg: to_binary(&call_message)?,
+            funds,
+        });
+
+        Ok(SubMsg::new(wasm_execute_message))
+    }
+
     pub fn x_cross_transfer(
         deps: DepsMut,
         env: Env,
@@ -430,9 +930,15 @@ mod execute {
         deps.api
             .addr_validate(account.as_ref())
             .map_err(ContractError::Std)?;
+        ensure_not_blocked(&deps, account.as_ref())?;
+
+        // hub amounts are rounded down to the token's decimals, dust below that precision stays on the hub
+        let decimals = TOKEN_INFO.load(deps.storage)?.decimals;
+        let amount = from_hub_amount(cross_transfer_data.value, decimals)?;
+        ensure!(amount > 0, ContractError::InvalidAmount);
         debug_println!("mint to {:?}", account);
 
-        let event = emit_cross_transfer_event(
+        let event = cross_transfer_event(
             "CrossTransfer".to_string(),
             cross_transfer_data.from,
             cross_transfer_data.to.clone(),
@@ -440,21 +946,30 @@ mod execute {
             cross_transfer_data.data,
         );
 
+        if let Some(token_factory) = TOKEN_FACTORY.may_load(deps.storage)? {
+            return Ok(Response::new()
+                .add_message(token_factory.mint(amount))
+                .add_message(token_factory.transfer(&account, amount))
+                .add_attribute("method", "x_cross_transfer")
+                .add_event(event));
+        }
+
         #[cfg(feature = "injective")]
         {
             let adapter = CW20_ADAPTER.load(deps.storage)?;
+            PENDING_ADAPTER_RECEIVE.save(
+                deps.storage,
+                &(cross_transfer_data.to.account(), amount.into()),
+            )?;
             let mut res = execute_mint(
                 deps,
                 env,
                 info.clone(),
                 adapter.adapter_contract().to_string(),
-                cross_transfer_data.value.into(),
+                amount.into(),
             )
             .expect("Fail to mint");
-            let receive_msg = adapter.receive(
-                &cross_transfer_data.to.account(),
-                cross_transfer_data.value.into(),
-            );
+            let receive_msg = adapter.receive(&cross_transfer_data.to.account(), amount.into());
             res = res
                 .add_submessage(receive_msg)
                 .add_attribute("method", "x_cross_transfer")
@@ -469,7 +984,7 @@ mod execute {
                 env,
                 info,
                 cross_transfer_data.to.account().to_string(),
-                cross_transfer_data.value.into(),
+                amount.into(),
             )
             .expect("Fail to mint");
 
@@ -497,15 +1012,47 @@ mod execute {
         deps.api
             .addr_validate(cross_transfer_revert_data.from.as_ref())
             .map_err(ContractError::Std)?;
-        let event = emit_cross_transfer_revert_event(
+        // the outbound amount is already burned, so refunds are credited even to blocked owners
+        // where the blocklist keeps them frozen
+        let event = cross_transfer_revert_event(
             "CrossTransferRevert".to_string(),
             cross_transfer_revert_data.from.clone(),
+            cross_transfer_revert_data.to.clone(),
             cross_transfer_revert_data.value,
+            cross_transfer_revert_data.data_hash.clone(),
+            cross_transfer_revert_data.nonce,
         );
 
+        if let Some(token_factory) = TOKEN_FACTORY.may_load(deps.storage)? {
+            let value = cross_transfer_revert_data.value;
+            let owner = cross_transfer_revert_data.from;
+            let res = Response::new()
+                .add_message(token_factory.mint(value))
+                .add_attribute("method", "x_cross_transfer_revert")
+                .add_event(event);
+            // a blocked owner's refund stays with this contract until the owner is unblocked
+            if is_blocked(deps.as_ref(), owner.as_str())? {
+                FROZEN_REFUNDS.update(deps.storage, owner.to_string(), |refund| {
+                    refund
+                        .unwrap_or_default()
+                        .checked_add(value)
+                        .ok_or(ContractError::InvalidAmount)
+                })?;
+                return Ok(res);
+            }
+            return Ok(res.add_message(token_factory.transfer(&owner, value)));
+        }
+
         #[cfg(feature = "injective")]
         {
             let adapter = CW20_ADAPTER.load(deps.storage)?;
+            PENDING_ADAPTER_RECEIVE.save(
+                deps.storage,
+                &(
+                    cross_transfer_revert_data.from.clone(),
+                    cross_transfer_revert_data.value.into(),
+                ),
+            )?;
             let mut res = execute_mint(
                 deps,
                 env,
@@ -543,6 +1090,25 @@ mod execute {
     }
 }
 
+fn is_blocked(deps: Deps, account: &str) -> StdResult<bool> {
+    Ok(BLOCKLIST
+        .may_load(deps.storage, account.to_string())?
+        .unwrap_or_default())
+}
+
+// contracts instantiated before the method names were configurable fall back to the defaults
+fn cross_transfer_method(storage: &dyn Storage) -> StdResult<String> {
+    Ok(X_CROSS_TRANSFER_METHOD
+        .may_load(storage)?
+        .unwrap_or_else(|| X_CROSS_TRANSFER.to_string()))
+}
+
+fn cross_transfer_revert_method(storage: &dyn Storage) -> StdResult<String> {
+    Ok(X_CROSS_TRANSFER_REVERT_METHOD
+        .may_load(storage)?
+        .unwrap_or_else(|| X_CROSS_TRANSFER_REVERT.to_string()))
+}
+
 fn setup_function(
     deps: DepsMut,
     _env: Env,
@@ -563,15 +1129,7 @@ fn setup_function(
         .save(deps.storage, &x_call)
         .map_err(ContractError::Std)?;
 
-    let query_message = GetNetworkAddress {};
-
-    let query: QueryRequest<Empty> = QueryRequest::Wasm(WasmQuery::Smart {
-        contract_addr: x_call.to_string(),
-        msg: to_binary(&query_message).map_err(ContractError::Std)?,
-    });
-
-    let x_call_network_address: NetworkAddress =
-        deps.querier.query(&query).map_err(ContractError::Std)?;
+    let x_call_network_address = XCallClient::new(x_call.clone()).network_address(&deps.querier)?;
 
     if x_call_network_address.to_string().is_empty() {
         return Err(ContractError::AddressNotFound);
@@ -585,7 +1143,11 @@ fn setup_function(
     DESTINATION_TOKEN_ADDRESS.save(deps.storage, &hub_address)?;
     DESTINATION_TOKEN_NET.save(deps.storage, &hub_net)?;
     X_CALL_MANAGER.save(deps.storage, &xcall_manager)?;
-    Ok(Response::default())
+    Ok(Response::new().add_event(xcall_configured_event(
+        &x_call,
+        &hub_network_address.to_string(),
+        &xcall_manager,
+    )))
 }
 
 #[cfg(test)]
@@ -630,16 +1192,35 @@ mod tests {
     use std::vec;
 
     use cosmwasm_std::{
+        from_binary,
         testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier},
-        to_binary, Addr, ContractResult, MemoryStorage, OwnedDeps, SystemResult, Uint128,
-        WasmQuery,
+        to_binary, Addr, Coin, ContractResult, CosmosMsg, MemoryStorage, OwnedDeps, SystemResult,
+        Uint128, WasmMsg, WasmQuery,
     };
-    use cw_common::xcall_manager_msg::{self, ProtocolConfig};
-    use cw_ibc_rlp_lib::rlp::encode;
+    use cw_common::hub_token_msg::TokenFactoryConfig;
+    use cw_common::xcall_client::mock::MockXCall;
+    use cw_ibc_rlp_lib::rlp::{decode, encode, RlpStream};
     use debug_print::debug_println;
 
     use super::*;
 
+    const GOVERNANCE: &str = "0x01.icon/cx0000000000000000000000000000000000000001";
+
+    fn instantiate_msg() -> InstantiateMsg {
+        InstantiateMsg {
+            x_call: "archway123fdth".to_owned(),
+            hub_address: "0x01.icon/cx9876543210fedcba9876543210fedcba98765432".to_owned(),
+            manager: Addr::unchecked("manager".to_string()),
+            marketing: None,
+            name: None,
+            symbol: None,
+            decimals: None,
+            cross_transfer_method: None,
+            cross_transfer_revert_method: None,
+            token_factory: None,
+        }
+    }
+
     fn setup(
         sender: &str,
     ) -> (
@@ -647,37 +1228,29 @@ mod tests {
         Env,
         MessageInfo,
     ) {
-        let mut deps: OwnedDeps<MemoryStorage, MockApi, MockQuerier> = mock_dependencies();
-        let env = mock_env();
-        let info = mock_info(sender, &[]);
-        let msg = InstantiateMsg {
-            x_call: "archway123fdth".to_owned(),
-            hub_address: "0x01.icon/cx9876543210fedcba9876543210fedcba98765432".to_owned(),
-            manager: Addr::unchecked("manager".to_string()),
-        };
+        setup_with_msg(sender, instantiate_msg())
+    }
 
-        deps.querier.update_wasm(|r| match r {
-            WasmQuery::Smart { contract_addr, msg } => {
-                if contract_addr == &"manager".to_owned() {
-                    if msg == &to_binary(&xcall_manager_msg::QueryMsg::GetProtocols {}).unwrap() {
-                        return SystemResult::Ok(ContractResult::Ok(
-                            to_binary(&ProtocolConfig {
-                                sources: vec![],
-                                destinations: vec![],
-                            })
-                            .unwrap(),
-                        ));
-                    }
+    fn setup_with_msg(
+        sender: &str,
+        msg: InstantiateMsg,
+    ) -> (
+        OwnedDeps<MemoryStorage, MockApi, MockQuerier>,
+        Env,
+        MessageInfo,
+    ) {
+        let mut deps: OwnedDeps<MemoryStorage, MockApi, MockQuerier> = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info(sender, &[]);
 
-                    SystemResult::Ok(ContractResult::Ok(to_binary(&true).unwrap()))
-                } else {
-                    SystemResult::Ok(ContractResult::Ok(
-                        to_binary("0x01.icon/cx9876543210fedcba9876543210fedcba98765432").unwrap(),
-                    ))
-                }
-            }
-            _ => todo!(),
-        });
+        let mut xcall_mock = MockXCall::new(
+            &msg.x_call,
+            "0x01.icon/cx9876543210fedcba9876543210fedcba98765432",
+            "manager",
+        );
+        xcall_mock.governance = GOVERNANCE.to_string();
+        deps.querier
+            .update_wasm(move |r| xcall_mock.query(r).expect("unexpected query"));
 
         let res = instantiate(deps.as_mut(), env.clone(), info.clone(), msg);
         debug_println!("res {:?}", res);
@@ -691,6 +1264,348 @@ mod tests {
         setup("archway123fdth");
     }
 
+    #[test]
+    fn instantiate_custom_token_test() {
+        let msg = InstantiateMsg {
+            name: Some("Balance Token".to_string()),
+            symbol: Some("BALN".to_string()),
+            decimals: Some(6),
+            cross_transfer_method: Some("xBalnTransfer".to_string()),
+            ..instantiate_msg()
+        };
+        let (mut deps, env, info) = setup_with_msg("archway123fdth", msg);
+
+        let token_info: cw20::TokenInfoResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::TokenInfo {}).unwrap())
+                .unwrap();
+        assert_eq!(token_info.name, "Balance Token");
+        assert_eq!(token_info.symbol, "BALN");
+        assert_eq!(token_info.decimals, 6);
+
+        let call_data = CrossTransfer {
+            method: "xCrossTransfer".to_string(),
+            from: NetworkAddress::from_str("0x01.icon/cx9876543210fedcba9876543210fedcba98765432")
+                .unwrap(),
+            to: NetworkAddress::from_str("0x01.icon/cx9876543210fedcba9876543210fedcba98765432")
+                .unwrap(),
+            value: 1000,
+            data: vec![],
+        };
+        let handle_call = |data: Vec<u8>| ExecuteMsg::HandleCallMessage {
+            from: NetworkAddress::from_str("0x01.icon/cx9876543210fedcba9876543210fedcba98765432")
+                .unwrap(),
+            data,
+            protocols: None,
+        };
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            handle_call(encode(&call_data).to_vec()),
+        );
+        assert!(matches!(res, Err(ContractError::InvalidMethod)));
+
+        let call_data = CrossTransfer {
+            method: "xBalnTransfer".to_string(),
+            ..call_data
+        };
+        let res = execute(
+            deps.as_mut(),
+            env,
+            info,
+            handle_call(encode(&call_data).to_vec()),
+        );
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn decimal_conversion_test() {
+        let msg = InstantiateMsg {
+            decimals: Some(6),
+            ..instantiate_msg()
+        };
+        let (mut deps, env, info) = setup_with_msg("archway123fdth", msg);
+        let hub = "0x01.icon/cx9876543210fedcba9876543210fedcba98765432";
+        let account = "cx9876543210fedcba9876543210fedcba98765452";
+
+        let x_cross_transfer = |value: u128| ExecuteMsg::HandleCallMessage {
+            from: NetworkAddress::from_str(hub).unwrap(),
+            data: encode(&CrossTransfer {
+                method: "xCrossTransfer".to_string(),
+                from: NetworkAddress::from_str(hub).unwrap(),
+                to: NetworkAddress::from_str(&format!("0x01.icon/{account}")).unwrap(),
+                value,
+                data: vec![],
+            })
+            .to_vec(),
+            protocols: None,
+        };
+
+        // hub amounts are rounded down to 6 decimals
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            x_cross_transfer(1_999_999_999_999),
+        )
+        .unwrap();
+        let balance: cw20::BalanceResponse = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::Balance {
+                    address: account.to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(balance.balance, Uint128::one());
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            x_cross_transfer(999_999_999_999),
+        );
+        assert!(matches!(res, Err(ContractError::InvalidAmount)));
+
+        // local amounts are scaled up to the hub's 18 decimals
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info(account, &[]),
+            ExecuteMsg::CrossTransfer {
+                to: NetworkAddress::from_str(hub).unwrap(),
+                amount: 1,
+                data: vec![],
+            },
+        )
+        .unwrap();
+        let data = res
+            .messages
+            .iter()
+            .find_map(|sub_msg| match &sub_msg.msg {
+                CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                    match from_binary::<XCallMsg>(msg) {
+                        Ok(XCallMsg::SendCallMessage { data, .. }) => Some(data),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .unwrap();
+        let call_data: CrossTransfer = decode(&data).unwrap();
+        assert_eq!(call_data.value, 1_000_000_000_000);
+    }
+
+    #[test]
+    fn token_factory_test() {
+        let msg = InstantiateMsg {
+            token_factory: Some(TokenFactoryConfig {
+                subdenom: "bnusd".to_string(),
+                proto_package: None,
+            }),
+            ..instantiate_msg()
+        };
+        let (mut deps, env, info) = setup_with_msg("archway123fdth", msg);
+        let token_factory =
+            TokenFactory::new(env.contract.address.clone(), "bnusd".to_string(), None);
+        let denom = token_factory.denom();
+
+        let queried: Option<String> =
+            from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::Denom {}).unwrap()).unwrap();
+        assert_eq!(queried, Some(denom.clone()));
+
+        let hub = "0x01.icon/cx9876543210fedcba9876543210fedcba98765432";
+        let account = "cx9876543210fedcba9876543210fedcba98765452";
+        let call_data = CrossTransfer {
+            method: "xCrossTransfer".to_string(),
+            from: NetworkAddress::from_str(hub).unwrap(),
+            to: NetworkAddress::from_str(&format!("0x01.icon/{account}")).unwrap(),
+            value: 1000,
+            data: vec![],
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::HandleCallMessage {
+                from: NetworkAddress::from_str(hub).unwrap(),
+                data: encode(&call_data).to_vec(),
+                protocols: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages[0].msg, token_factory.mint(1000));
+        assert_eq!(
+            res.messages[1].msg,
+            token_factory.transfer(&Addr::unchecked(account), 1000)
+        );
+
+        let cross_transfer = ExecuteMsg::CrossTransfer {
+            to: NetworkAddress::from_str(hub).unwrap(),
+            amount: 400,
+            data: vec![],
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info(account, &[Coin::new(300, denom.clone())]),
+            cross_transfer.clone(),
+        );
+        assert!(matches!(res, Err(ContractError::InvalidAmount)));
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info(account, &[Coin::new(400, denom)]),
+            cross_transfer,
+        )
+        .unwrap();
+        assert_eq!(res.messages[0].msg, token_factory.burn(400));
+    }
+
+    #[test]
+    fn token_factory_compliance_test() {
+        let msg = InstantiateMsg {
+            token_factory: Some(TokenFactoryConfig {
+                subdenom: "bnusd".to_string(),
+                proto_package: None,
+            }),
+            ..instantiate_msg()
+        };
+        let (mut deps, env, info) = setup_with_msg("archway123fdth", msg);
+        let token_factory =
+            TokenFactory::new(env.contract.address.clone(), "bnusd".to_string(), None);
+        let hub = "0x01.icon/cx9876543210fedcba9876543210fedcba98765432";
+        let account = "cx9876543210fedcba9876543210fedcba98765452";
+        let handle_call = |data: Vec<u8>| ExecuteMsg::HandleCallMessage {
+            from: NetworkAddress::from_str(hub).unwrap(),
+            data,
+            protocols: None,
+        };
+
+        let block = ExecuteMsg::Block {
+            address: account.to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), block).unwrap();
+
+        // blocked recipients receive neither inbound transfers nor mints
+        let call_data = CrossTransfer {
+            method: "xCrossTransfer".to_string(),
+            from: NetworkAddress::from_str(hub).unwrap(),
+            to: NetworkAddress::from_str(&format!("0x01.icon/{account}")).unwrap(),
+            value: 1000,
+            data: vec![],
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            handle_call(encode(&call_data).to_vec()),
+        );
+        assert!(matches!(res, Err(ContractError::AddressBlocked { .. })));
+        let mint = ExecuteMsg::Mint {
+            recipient: account.to_string(),
+            amount: Uint128::new(100),
+        };
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), mint);
+        assert!(matches!(res, Err(ContractError::AddressBlocked { .. })));
+
+        // a blocked owner's refund is minted to the contract and held
+        let revert_data = CrossTransferRevert {
+            method: "xCrossTransferRevert".to_string(),
+            from: Addr::unchecked(account),
+            value: 300,
+            to: None,
+            data_hash: vec![],
+            nonce: 1,
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            handle_call(encode(&revert_data).to_vec()),
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, token_factory.mint(300));
+        let frozen_refund = |deps: Deps| -> Uint128 {
+            from_binary(
+                &query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::FrozenRefund {
+                        address: account.to_string(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap()
+        };
+        assert_eq!(frozen_refund(deps.as_ref()), Uint128::new(300));
+
+        let unblock = ExecuteMsg::Unblock {
+            address: account.to_string(),
+        };
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), unblock).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            token_factory.transfer(&Addr::unchecked(account), 300)
+        );
+        assert_eq!(frozen_refund(deps.as_ref()), Uint128::zero());
+
+        // only the minter mints, and it is paid in the denom
+        let mint = ExecuteMsg::Mint {
+            recipient: account.to_string(),
+            amount: Uint128::new(100),
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            mint.clone(),
+        );
+        assert!(matches!(res, Err(ContractError::Unauthorized)));
+        let res = execute(deps.as_mut(), env.clone(), info, mint).unwrap();
+        assert_eq!(res.messages[0].msg, token_factory.mint(100));
+        assert_eq!(
+            res.messages[1].msg,
+            token_factory.transfer(&Addr::unchecked(account), 100)
+        );
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("alice", &[]),
+            ExecuteMsg::CrossTransferFrom {
+                owner: account.to_string(),
+                to: NetworkAddress::from_str(hub).unwrap(),
+                amount: 100,
+                data: vec![],
+            },
+        );
+        assert!(matches!(res, Err(ContractError::TokenFactoryUnsupported)));
+    }
+
+    #[test]
+    fn instantiate_invalid_symbol_test() {
+        let mut deps = mock_dependencies();
+        let msg = InstantiateMsg {
+            symbol: Some("b".to_string()),
+            ..instantiate_msg()
+        };
+        let res = instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("archway123fdth", &[]),
+            msg,
+        );
+        assert!(res.is_err());
+    }
+
     #[test]
     fn execute_handle_call_x_cross_transfer_test() {
         let (mut deps, env, info) = setup("archway123fdth");
@@ -724,6 +1639,133 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[cfg(feature = "injective")]
+    #[test]
+    fn adapter_receive_failure_test() {
+        let (mut deps, env, info) = setup("archway123fdth");
+        let recipient = "cx9876543210fedcba9876543210fedcba98765452";
+
+        let set_adapter = ExecuteMsg::SetAdapter {
+            registry_contract: "adapter".to_string(),
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            set_adapter.clone(),
+        );
+        assert!(matches!(res, Err(ContractError::Unauthorized)));
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), set_adapter).unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        let call_data = CrossTransfer {
+            method: "xCrossTransfer".to_string(),
+            from: NetworkAddress::from_str("0x01.icon/cx9876543210fedcba9876543210fedcba98765432")
+                .unwrap(),
+            to: NetworkAddress::from_str(&format!("0x01.icon/{recipient}")).unwrap(),
+            value: 1000,
+            data: vec![],
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info,
+            ExecuteMsg::HandleCallMessage {
+                from: NetworkAddress::from_str(
+                    "0x01.icon/cx9876543210fedcba9876543210fedcba98765432",
+                )
+                .unwrap(),
+                data: encode(&call_data).to_vec(),
+                protocols: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages[0].id, ADAPTER_RECEIVE_REPLY_ID);
+
+        reply(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: ADAPTER_RECEIVE_REPLY_ID,
+                result: SubMsgResult::Err("adapter failure".to_string()),
+            },
+        )
+        .unwrap();
+
+        let balance = |address: &str| -> Uint128 {
+            let res: cw20::BalanceResponse = from_binary(
+                &query(
+                    deps.as_ref(),
+                    env.clone(),
+                    QueryMsg::Balance {
+                        address: address.to_string(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            res.balance
+        };
+        assert_eq!(balance(recipient), Uint128::new(1000));
+        assert_eq!(balance("adapter"), Uint128::zero());
+    }
+
+    #[cfg(feature = "injective")]
+    #[test]
+    fn adapter_redeem_failure_test() {
+        let (mut deps, env, info) = setup("archway123fdth");
+
+        let set_adapter = ExecuteMsg::SetAdapter {
+            registry_contract: "adapter".to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), set_adapter).unwrap();
+        let adapter: AdapterResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::GetAdapter {}).unwrap())
+                .unwrap();
+
+        // cw20 backing the tokenfactory denom held by alice
+        let mint = ExecuteMsg::Mint {
+            recipient: "adapter".to_string(),
+            amount: Uint128::from(400u128),
+        };
+        execute(deps.as_mut(), env.clone(), info, mint).unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[Coin::new(400, adapter.denom)]),
+            ExecuteMsg::CrossTransfer {
+                to: NetworkAddress::from_str(
+                    "0x01.icon/cx9876543210fedcba9876543210fedcba98765432",
+                )
+                .unwrap(),
+                amount: 400,
+                data: vec![],
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages[0].id, ADAPTER_REDEEM_REPLY_ID);
+
+        reply(
+            deps.as_mut(),
+            env.clone(),
+            Reply {
+                id: ADAPTER_REDEEM_REPLY_ID,
+                result: SubMsgResult::Err("adapter failure".to_string()),
+            },
+        )
+        .unwrap();
+
+        // the burn following the redeem can now take the cross transferred amount
+        let balance = |address: &str| query_balance(deps.as_ref(), address.to_string()).unwrap();
+        assert_eq!(balance("alice").balance, Uint128::new(400));
+        assert_eq!(balance("adapter").balance, Uint128::zero());
+        assert!(PENDING_ADAPTER_REDEEM
+            .may_load(&deps.storage)
+            .unwrap()
+            .is_none());
+    }
+
     #[test]
     fn cross_transfer_test() {
         let (mut deps, env, info) = setup("archway123fdth");
@@ -775,6 +1817,533 @@ mod tests {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn cross_transfer_from_test() {
+        let (mut deps, env, info) = setup("archway123fdth");
+
+        let mint = ExecuteMsg::Mint {
+            recipient: "alice".to_string(),
+            amount: Uint128::from(1000u128),
+        };
+        execute(deps.as_mut(), env.clone(), info, mint).unwrap();
+
+        let cross_transfer_from = ExecuteMsg::CrossTransferFrom {
+            owner: "alice".to_string(),
+            to: NetworkAddress::from_str("0x01.icon/cx9876543210fedcba9876543210fedcba98765432")
+                .unwrap(),
+            amount: 400,
+            data: vec![],
+        };
+
+        // no allowance has been granted yet
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("router", &[]),
+            cross_transfer_from.clone(),
+        );
+        assert!(res.is_err());
+
+        let allowance = ExecuteMsg::IncreaseAllowance {
+            spender: "router".to_string(),
+            amount: Uint128::from(500u128),
+            expires: None,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            allowance,
+        )
+        .unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env,
+            mock_info("router", &[]),
+            cross_transfer_from,
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        let alice_balance = query_balance(deps.as_ref(), "alice".to_string()).unwrap();
+        assert_eq!(alice_balance.balance, Uint128::from(600u128));
+        let allowance =
+            query_allowance(deps.as_ref(), "alice".to_string(), "router".to_string()).unwrap();
+        assert_eq!(allowance.allowance, Uint128::from(100u128));
+    }
+
+    #[test]
+    fn pause_test() {
+        let (mut deps, env, info) = setup("archway123fdth");
+
+        let mint = ExecuteMsg::Mint {
+            recipient: "alice".to_string(),
+            amount: Uint128::from(1000u128),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), mint).unwrap();
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            ExecuteMsg::Pause {},
+        );
+        assert!(matches!(res, Err(ContractError::Unauthorized)));
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::Pause {},
+        )
+        .unwrap();
+        assert_eq!(res.events, vec![crate::events::paused_updated_event(true)]);
+        let paused: bool =
+            from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::IsPaused {}).unwrap())
+                .unwrap();
+        assert!(paused);
+
+        let transfer = ExecuteMsg::Transfer {
+            recipient: "bob".to_string(),
+            amount: Uint128::from(100u128),
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            transfer.clone(),
+        );
+        assert!(matches!(res, Err(ContractError::Paused)));
+
+        let cross_transfer = ExecuteMsg::CrossTransfer {
+            to: NetworkAddress::from_str("0x01.icon/cx9876543210fedcba9876543210fedcba98765432")
+                .unwrap(),
+            amount: 100,
+            data: vec![],
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            cross_transfer,
+        );
+        assert!(matches!(res, Err(ContractError::Paused)));
+
+        let burn = ExecuteMsg::Burn {
+            amount: Uint128::from(100u128),
+        };
+        let res = execute(deps.as_mut(), env.clone(), mock_info("alice", &[]), burn);
+        assert!(matches!(res, Err(ContractError::Paused)));
+
+        execute(deps.as_mut(), env.clone(), info, ExecuteMsg::Unpause {}).unwrap();
+        execute(deps.as_mut(), env, mock_info("alice", &[]), transfer).unwrap();
+
+        let bob_balance = query_balance(deps.as_ref(), "bob".to_string()).unwrap();
+        assert_eq!(bob_balance.balance, Uint128::from(100u128));
+    }
+
+    #[test]
+    fn recipient_validation_test() {
+        let (mut deps, env, info) = setup("archway123fdth");
+        let mint = ExecuteMsg::Mint {
+            recipient: "alice".to_string(),
+            amount: Uint128::from(1000u128),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), mint).unwrap();
+
+        let cross_transfer = |to: &str| ExecuteMsg::CrossTransfer {
+            to: NetworkAddress::from_str(to).unwrap(),
+            amount: 100,
+            data: vec![],
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            cross_transfer("0x1.eth/0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+        );
+        assert!(matches!(res, Err(ContractError::InvalidToAddress)));
+
+        // chains without a known or configured format are rejected
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            cross_transfer("0x1.unknown/0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+        );
+        assert!(matches!(res, Err(ContractError::InvalidToAddress)));
+
+        let recipient = "injective-1/archway1qypqxpq9qcrsszg2pvxq6rs0zqg3yyc52fs6vt";
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            cross_transfer(recipient),
+        );
+        assert!(matches!(res, Err(ContractError::InvalidToAddress)));
+
+        let configure_format = ExecuteMsg::ConfigureRecipientFormat {
+            nid: "injective-1".to_string(),
+            format: Some(AddressFormat::Bech32 {
+                prefix: "archway".to_string(),
+            }),
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            configure_format.clone(),
+        );
+        assert!(matches!(res, Err(ContractError::Unauthorized)));
+        execute(deps.as_mut(), env.clone(), info, configure_format).unwrap();
+
+        let format: Option<AddressFormat> = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::GetRecipientFormat {
+                    nid: "injective-1".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            format,
+            Some(AddressFormat::Bech32 {
+                prefix: "archway".to_string()
+            })
+        );
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info("alice", &[]),
+            cross_transfer(recipient),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn blocklist_test() {
+        let (mut deps, env, info) = setup("archway123fdth");
+
+        let mint = ExecuteMsg::Mint {
+            recipient: "alice".to_string(),
+            amount: Uint128::from(1000u128),
+        };
+        execute(deps.as_mut(), env.clone(), info.clone(), mint).unwrap();
+
+        let block = ExecuteMsg::Block {
+            address: "bob".to_string(),
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            block.clone(),
+        );
+        assert!(matches!(res, Err(ContractError::Unauthorized)));
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), block).unwrap();
+        assert_eq!(
+            res.events,
+            vec![crate::events::blocklist_updated_event(
+                &Addr::unchecked("bob"),
+                true,
+                0
+            )]
+        );
+
+        let blocked: bool = from_binary(
+            &query(
+                deps.as_ref(),
+                env.clone(),
+                QueryMsg::IsBlocked {
+                    address: "bob".to_string(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(blocked);
+
+        // blocked addresses can neither receive nor send
+        let transfer = ExecuteMsg::Transfer {
+            recipient: "bob".to_string(),
+            amount: Uint128::from(100u128),
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            transfer.clone(),
+        );
+        assert!(matches!(res, Err(ContractError::AddressBlocked { .. })));
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bob", &[]),
+            ExecuteMsg::CrossTransfer {
+                to: NetworkAddress::from_str(
+                    "0x01.icon/cx9876543210fedcba9876543210fedcba98765432",
+                )
+                .unwrap(),
+                amount: 100,
+                data: vec![],
+            },
+        );
+        assert!(matches!(res, Err(ContractError::AddressBlocked { .. })));
+
+        // inbound mints to blocked addresses are rejected
+        let call_data = CrossTransfer {
+            method: "xCrossTransfer".to_string(),
+            from: NetworkAddress::from_str("0x01.icon/cx9876543210fedcba9876543210fedcba98765432")
+                .unwrap(),
+            to: NetworkAddress::from_str("0x01.icon/bob").unwrap(),
+            value: 1000,
+            data: vec![],
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::HandleCallMessage {
+                from: NetworkAddress::from_str(
+                    "0x01.icon/cx9876543210fedcba9876543210fedcba98765432",
+                )
+                .unwrap(),
+                data: encode(&call_data).to_vec(),
+                protocols: None,
+            },
+        );
+        assert!(matches!(res, Err(ContractError::AddressBlocked { .. })));
+
+        // rollbacks still refund a blocked owner, the refund stays frozen
+        let revert_data = CrossTransferRevert {
+            method: "xCrossTransferRevert".to_string(),
+            from: Addr::unchecked("bob"),
+            value: 300,
+            to: None,
+            data_hash: vec![],
+            nonce: 1,
+        };
+        execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            ExecuteMsg::HandleCallMessage {
+                from: NetworkAddress::from_str(
+                    "0x01.icon/cx9876543210fedcba9876543210fedcba98765432",
+                )
+                .unwrap(),
+                data: encode(&revert_data).to_vec(),
+                protocols: None,
+            },
+        )
+        .unwrap();
+        let bob_balance = query_balance(deps.as_ref(), "bob".to_string()).unwrap();
+        assert_eq!(bob_balance.balance, Uint128::from(300u128));
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("bob", &[]),
+            ExecuteMsg::Burn {
+                amount: Uint128::from(100u128),
+            },
+        );
+        assert!(matches!(res, Err(ContractError::AddressBlocked { .. })));
+
+        let unblock = ExecuteMsg::Unblock {
+            address: "bob".to_string(),
+        };
+        execute(deps.as_mut(), env.clone(), info, unblock).unwrap();
+        execute(deps.as_mut(), env, mock_info("alice", &[]), transfer).unwrap();
+    }
+
+    #[test]
+    fn marketing_test() {
+        let (mut deps, env, info) = setup("archway123fdth");
+
+        let update = ExecuteMsg::UpdateMarketing {
+            project: Some("Balanced".to_string()),
+            description: Some("Balanced Dollar".to_string()),
+            marketing: None,
+        };
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            update.clone(),
+        );
+        assert!(matches!(res, Err(ContractError::Unauthorized)));
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), update).unwrap();
+        let event = &res.events[0];
+        assert_eq!(event.ty, crate::events::MARKETING_UPDATED);
+        assert_eq!(event.attributes[0].value, "Balanced");
+        assert_eq!(event.attributes[1].value, "Balanced Dollar");
+        assert_eq!(event.attributes[2].value, "");
+
+        let logo = ExecuteMsg::UploadLogo(cw20::Logo::Url(
+            "https://balanced.network/logo.png".to_string(),
+        ));
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            logo.clone(),
+        );
+        assert!(matches!(res, Err(ContractError::Unauthorized)));
+        let res = execute(deps.as_mut(), env.clone(), info.clone(), logo).unwrap();
+        let event = &res.events[0];
+        assert_eq!(event.ty, crate::events::LOGO_UPLOADED);
+        assert_eq!(
+            event.attributes[0].value,
+            "https://balanced.network/logo.png"
+        );
+
+        let marketing_info: cw20::MarketingInfoResponse =
+            from_binary(&query(deps.as_ref(), env.clone(), QueryMsg::MarketingInfo {}).unwrap())
+                .unwrap();
+        assert_eq!(marketing_info.project, Some("Balanced".to_string()));
+        assert_eq!(
+            marketing_info.description,
+            Some("Balanced Dollar".to_string())
+        );
+        assert_eq!(
+            marketing_info.logo,
+            Some(cw20::LogoInfo::Url(
+                "https://balanced.network/logo.png".to_string()
+            ))
+        );
+
+        let clear = ExecuteMsg::UpdateMarketing {
+            project: Some("".to_string()),
+            description: None,
+            marketing: None,
+        };
+        execute(deps.as_mut(), env.clone(), info, clear).unwrap();
+        let marketing_info: cw20::MarketingInfoResponse =
+            from_binary(&query(deps.as_ref(), env, QueryMsg::MarketingInfo {}).unwrap()).unwrap();
+        assert_eq!(marketing_info.project, None);
+        assert_eq!(
+            marketing_info.description,
+            Some("Balanced Dollar".to_string())
+        );
+    }
+
+    #[test]
+    fn malformed_call_message_test() {
+        let (mut deps, env, info) = setup("archway123fdth");
+        let list = |items: &[&[u8]]| {
+            let mut stream = RlpStream::new_list(items.len());
+            items.iter().for_each(|item| {
+                stream.append(&item.to_vec());
+            });
+            stream.out().to_vec()
+        };
+
+        let payloads = vec![
+            // not a list
+            vec![0x83, 1, 2, 3],
+            // method isn't utf8
+            list(&[&[0xff, 0xfe], b"a", b"b"]),
+            // too few fields for a cross transfer
+            list(&[b"xCrossTransfer", b"a", b"b"]),
+            list(&[b"xCrossTransferRevert", b"a", b"b"]),
+        ];
+        for data in payloads {
+            let res = execute(
+                deps.as_mut(),
+                env.clone(),
+                info.clone(),
+                ExecuteMsg::HandleCallMessage {
+                    from: NetworkAddress::from_str(
+                        "0x01.icon/cx9876543210fedcba9876543210fedcba98765432",
+                    )
+                    .unwrap(),
+                    data,
+                    protocols: None,
+                },
+            );
+            assert!(matches!(res, Err(ContractError::InvalidData)));
+        }
+    }
+
+    #[test]
+    fn configure_hub_test() {
+        let (mut deps, env, info) = setup("archway123fdth");
+        let new_hub = "0x01.icon/cx0000000000000000000000000000000000000002";
+
+        let configure_hub = |from: &str| ExecuteMsg::HandleCallMessage {
+            from: NetworkAddress::from_str(from).unwrap(),
+            data: encode(&ConfigureHub {
+                method: CONFIGURE_HUB.to_string(),
+                x_call: "".to_string(),
+                hub_address: new_hub.to_string(),
+                manager: "".to_string(),
+            })
+            .to_vec(),
+            protocols: None,
+        };
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            configure_hub("0x01.icon/cx9876543210fedcba9876543210fedcba98765432"),
+        );
+        assert!(matches!(res, Err(ContractError::OnlyGovernance)));
+
+        let res = execute(
+            deps.as_mut(),
+            env.clone(),
+            mock_info("alice", &[]),
+            configure_hub(GOVERNANCE),
+        );
+        assert!(matches!(res, Err(ContractError::OnlyCallService)));
+
+        let res = execute(deps.as_mut(), env, info, configure_hub(GOVERNANCE)).unwrap();
+        let event = res
+            .events
+            .iter()
+            .find(|event| event.ty == crate::events::HUB_CONFIGURED)
+            .unwrap();
+        assert_eq!(event.attributes[1].value, new_hub);
+        let hub_address = DESTINATION_TOKEN_ADDRESS
+            .load(deps.as_ref().storage)
+            .unwrap();
+        assert_eq!(
+            hub_address.as_str(),
+            "cx0000000000000000000000000000000000000002"
+        );
+        assert_eq!(
+            X_CALL.load(deps.as_ref().storage).unwrap(),
+            Addr::unchecked("archway123fdth")
+        );
+        assert_eq!(
+            X_CALL_MANAGER.load(deps.as_ref().storage).unwrap(),
+            Addr::unchecked("manager")
+        );
+    }
+
+    #[test]
+    fn setup_only_owner() {
+        let (mut deps, env, _) = setup("archway123fdth");
+
+        let setup_message = ExecuteMsg::Setup {
+            x_call: Addr::unchecked("archwayxcalladdress".to_owned()),
+            hub_address: NetworkAddress::from_str(
+                "0x01.icon/cx9876543210fedcba9876543210fedcba98765432",
+            )
+            .unwrap(),
+            manager: Addr::unchecked("manager".to_string()),
+        };
+
+        let res = execute(deps.as_mut(), env, mock_info("alice", &[]), setup_message);
+        assert!(matches!(res, Err(ContractError::Unauthorized)));
+    }
+
     #[test]
     fn change_xcall_address() {
         let (mut deps, env, info) = setup("archway123fdth");
@@ -866,6 +2435,12 @@ mod tests {
                 "0x01.icon/cx9876543210fedcba9876543210fedcba98765432".to_owned(),
             ),
             value: 1000,
+            to: Some(
+                NetworkAddress::from_str("0x01.icon/hx9876543210fedcba9876543210fedcba98765432")
+                    .unwrap(),
+            ),
+            data_hash: vec![1, 2, 3],
+            nonce: 5,
         };
         let data = encode(&call_data).to_vec();
 
@@ -881,7 +2456,28 @@ mod tests {
                 data,
                 protocols: None,
             },
+        )
+        .unwrap();
+
+        let event = res
+            .events
+            .iter()
+            .find(|e| e.ty == "CrossTransferRevert")
+            .unwrap();
+        let attribute = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|a| a.key == key)
+                .unwrap()
+                .value
+                .clone()
+        };
+        assert_eq!(
+            attribute("to"),
+            "0x01.icon/hx9876543210fedcba9876543210fedcba98765432"
         );
-        assert!(res.is_ok());
+        assert_eq!(attribute("data_hash"), "010203");
+        assert_eq!(attribute("nonce"), "5");
     }
 }
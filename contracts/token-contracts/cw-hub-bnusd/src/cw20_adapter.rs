@@ -1,17 +1,23 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::SubMsg;
 use cosmwasm_std::{coin, to_binary, Addr, BankMsg, Binary, CosmosMsg, WasmMsg};
 use cosmwasm_std::{Coin, MessageInfo};
+use cosmwasm_std::{ReplyOn, SubMsg};
 //use cw20_adapter::msg::ExecuteMsg as Cw20AdapterMsg;
-use cw_common::hub_token_msg::ExecuteMsg as TokenExecuteMsg;
 use cosmwasm_std::Uint128;
+use cw_common::hub_token_msg::ExecuteMsg as TokenExecuteMsg;
+
+use crate::constants::{ADAPTER_RECEIVE_REPLY_ID, ADAPTER_REDEEM_REPLY_ID};
 
 #[cw_serde]
 pub enum Cw20AdapterMsg {
     /// Registers a new CW-20 contract that will be handled by the adapter
     RegisterCw20Contract { addr: Addr },
     ///  Impl of Receiver CW-20 interface. Should be called by CW-20 contract only!! (never directly). Msg is ignored
-    Receive { sender: String, amount: Uint128, msg: Binary },
+    Receive {
+        sender: String,
+        amount: Uint128,
+        msg: Binary,
+    },
     /// Called to redeem TF tokens. Will send CW-20 tokens to "recipient" address (or sender if not provided). Will use transfer method
     RedeemAndTransfer { recipient: Option<String> },
     /// Called to redeem TF tokens. Will call Send method of CW:20 to send CW-20 tokens to "recipient" address. Submessage will be passed to send method (can be empty)
@@ -33,6 +39,17 @@ impl CW20Adapter {
             adapter_contract,
         };
     }
+    // register this token with the adapter so it creates the TF denom, funds cover the denom creation fee
+    pub fn register(&self, funds: Vec<Coin>) -> CosmosMsg {
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: self.adapter_contract.to_string(),
+            msg: to_binary(&Cw20AdapterMsg::RegisterCw20Contract {
+                addr: self.token_contract.clone(),
+            })
+            .unwrap(),
+            funds,
+        })
+    }
     // convert specified TF tokens to our token and transfer to receiver
     pub fn redeem(&self, amount: u128, receiver: &Addr) -> SubMsg {
         let fund = coin(amount, self.denom());
@@ -46,10 +63,10 @@ impl CW20Adapter {
             funds: vec![fund],
         });
         let submessage = SubMsg {
-            id: 2,
+            id: ADAPTER_REDEEM_REPLY_ID,
             msg,
             gas_limit: None,
-            reply_on: cosmwasm_std::ReplyOn::Never,
+            reply_on: ReplyOn::Always,
         };
         submessage
     }
@@ -66,10 +83,10 @@ impl CW20Adapter {
             funds: vec![],
         });
         let submessage = SubMsg {
-            id: 1,
+            id: ADAPTER_RECEIVE_REPLY_ID,
             msg,
             gas_limit: None,
-            reply_on: cosmwasm_std::ReplyOn::Never,
+            reply_on: ReplyOn::Always,
         };
         submessage
     }
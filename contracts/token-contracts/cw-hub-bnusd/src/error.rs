@@ -22,6 +22,8 @@ pub enum ContractError {
     OnlyCallService,
     #[error("OnlyHub")]
     OnlyHub,
+    #[error("OnlyGovernance")]
+    OnlyGovernance,
     #[error("Invalid Method")]
     InvalidMethod,
     #[error("Issue in Minting of Token")]
@@ -36,6 +38,14 @@ pub enum ContractError {
     Cw20BaseError(#[from] cw20_base::ContractError),
     #[error("Cannot Send to Self")]
     CannotSendToSelf,
+    #[error("Token Transfers Are Paused")]
+    Paused,
+    #[error("Address Is Blocked: {address}")]
+    AddressBlocked { address: String },
+    #[error("Unknown Reply Id: {id}")]
+    UnknownReplyId { id: u64 },
+    #[error("Not Supported For Tokenfactory Denoms")]
+    TokenFactoryUnsupported,
     // Add any other custom errors you like here.
     // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
 }
@@ -1,8 +1,17 @@
 use cosmwasm_std::{Addr, Event};
+use cw20::{LogoInfo, MarketingInfoResponse};
 use cw_common::network_address::NetworkAddress;
 use debug_print::debug_println;
 
-pub fn emit_cross_transfer_event(
+// owner and governance paths of the hub token
+pub const PAUSED_UPDATED: &str = "PausedUpdated";
+pub const BLOCKLIST_UPDATED: &str = "BlocklistUpdated";
+pub const ADAPTER_CONFIGURED: &str = "AdapterConfigured";
+pub const HUB_CONFIGURED: &str = "HubConfigured";
+pub const MARKETING_UPDATED: &str = "MarketingUpdated";
+pub const LOGO_UPLOADED: &str = "LogoUploaded";
+
+pub fn cross_transfer_event(
     name: String,
     from: NetworkAddress,
     to: NetworkAddress,
@@ -16,10 +25,71 @@ pub fn emit_cross_transfer_event(
         .add_attribute("data", hex_encode(data))
 }
 
-pub fn emit_cross_transfer_revert_event(name: String, from: Addr, amount: u128) -> Event {
+pub fn cross_transfer_revert_event(
+    name: String,
+    from: Addr,
+    to: Option<NetworkAddress>,
+    amount: u128,
+    data_hash: Vec<u8>,
+    nonce: u128,
+) -> Event {
+    let to = to
+        .map(|to| to.to_string())
+        .unwrap_or_else(|| "null".to_string());
     Event::new(name)
         .add_attribute("from", from.to_string())
+        .add_attribute("to", to)
         .add_attribute("value", amount.to_string())
+        .add_attribute("data_hash", hex_encode(data_hash))
+        .add_attribute("nonce", nonce.to_string())
+}
+
+pub fn paused_updated_event(paused: bool) -> Event {
+    Event::new(PAUSED_UPDATED).add_attribute("paused", paused.to_string())
+}
+
+// refund is the frozen tokenfactory refund released when the address is unblocked, 0 if none
+pub fn blocklist_updated_event(address: &Addr, blocked: bool, refund: u128) -> Event {
+    Event::new(BLOCKLIST_UPDATED)
+        .add_attribute("address", address.to_string())
+        .add_attribute("blocked", blocked.to_string())
+        .add_attribute("refund", refund.to_string())
+}
+
+pub fn adapter_configured_event(adapter: &Addr, denom: &str) -> Event {
+    Event::new(ADAPTER_CONFIGURED)
+        .add_attribute("adapter", adapter.to_string())
+        .add_attribute("denom", denom)
+}
+
+pub fn hub_configured_event(x_call: &Addr, hub_address: &NetworkAddress, manager: &Addr) -> Event {
+    Event::new(HUB_CONFIGURED)
+        .add_attribute("x_call", x_call.to_string())
+        .add_attribute("hub_address", hub_address.to_string())
+        .add_attribute("manager", manager.to_string())
+}
+
+// carries the marketing info after the update, cleared fields are empty
+pub fn marketing_updated_event(info: &MarketingInfoResponse) -> Event {
+    Event::new(MARKETING_UPDATED)
+        .add_attribute("project", info.project.clone().unwrap_or_default())
+        .add_attribute("description", info.description.clone().unwrap_or_default())
+        .add_attribute(
+            "marketing",
+            info.marketing
+                .as_ref()
+                .map(|marketing| marketing.to_string())
+                .unwrap_or_default(),
+        )
+}
+
+// embedded logos are served by the DownloadLogo query, only urls are worth indexing
+pub fn logo_uploaded_event(logo: &LogoInfo) -> Event {
+    let logo = match logo {
+        LogoInfo::Url(url) => url.as_str(),
+        LogoInfo::Embedded => "embedded",
+    };
+    Event::new(LOGO_UPLOADED).add_attribute("logo", logo)
 }
 
 fn hex_encode(data: Vec<u8>) -> String {
@@ -5,5 +5,7 @@ pub mod cw20_adapter;
 mod error;
 pub mod events;
 pub mod helpers;
+pub mod marketing;
 pub mod state;
+pub mod token_factory;
 pub use crate::error::ContractError;
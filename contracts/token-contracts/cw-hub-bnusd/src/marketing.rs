@@ -0,0 +1,160 @@
+use cosmwasm_std::{DepsMut, MessageInfo, Response, Storage};
+use cw20::{EmbeddedLogo, Logo, LogoInfo, MarketingInfoResponse};
+use cw20_base::msg::InstantiateMarketingInfo;
+use cw20_base::state::{LOGO, MARKETING_INFO};
+
+use crate::error::ContractError;
+use crate::events::{logo_uploaded_event, marketing_updated_event};
+use crate::state::OWNER;
+
+const LOGO_SIZE_CAP: usize = 5 * 1024;
+const PNG_HEADER: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+pub fn instantiate_marketing(
+    deps: &mut DepsMut,
+    marketing: InstantiateMarketingInfo,
+) -> Result<(), ContractError> {
+    let logo = match marketing.logo {
+        Some(logo) => Some(save_logo(deps.storage, logo)?),
+        None => None,
+    };
+
+    let data = MarketingInfoResponse {
+        project: marketing.project,
+        description: marketing.description,
+        marketing: marketing
+            .marketing
+            .map(|addr| deps.api.addr_validate(&addr))
+            .transpose()?,
+        logo,
+    };
+    MARKETING_INFO.save(deps.storage, &data)?;
+    Ok(())
+}
+
+pub fn update_marketing(
+    deps: DepsMut,
+    info: MessageInfo,
+    project: Option<String>,
+    description: Option<String>,
+    marketing: Option<String>,
+) -> Result<Response, ContractError> {
+    ensure_owner(&deps, &info)?;
+
+    let mut marketing_info = MARKETING_INFO.may_load(deps.storage)?.unwrap_or_default();
+
+    // an empty string clears the field, None leaves it unchanged
+    if let Some(project) = project {
+        marketing_info.project = Some(project).filter(|p| !p.trim().is_empty());
+    }
+    if let Some(description) = description {
+        marketing_info.description = Some(description).filter(|d| !d.trim().is_empty());
+    }
+    if let Some(marketing) = marketing {
+        marketing_info.marketing = match marketing.trim().is_empty() {
+            true => None,
+            false => Some(deps.api.addr_validate(&marketing)?),
+        };
+    }
+
+    if marketing_info.project.is_none()
+        && marketing_info.description.is_none()
+        && marketing_info.marketing.is_none()
+        && marketing_info.logo.is_none()
+    {
+        MARKETING_INFO.remove(deps.storage);
+    } else {
+        MARKETING_INFO.save(deps.storage, &marketing_info)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "update_marketing")
+        .add_event(marketing_updated_event(&marketing_info)))
+}
+
+pub fn upload_logo(
+    deps: DepsMut,
+    info: MessageInfo,
+    logo: Logo,
+) -> Result<Response, ContractError> {
+    ensure_owner(&deps, &info)?;
+
+    let logo_info = save_logo(deps.storage, logo)?;
+    let mut marketing_info = MARKETING_INFO.may_load(deps.storage)?.unwrap_or_default();
+    let event = logo_uploaded_event(&logo_info);
+    marketing_info.logo = Some(logo_info);
+    MARKETING_INFO.save(deps.storage, &marketing_info)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "upload_logo")
+        .add_event(event))
+}
+
+fn ensure_owner(deps: &DepsMut, info: &MessageInfo) -> Result<(), ContractError> {
+    let owner = OWNER.load(deps.storage)?;
+    if owner != info.sender {
+        return Err(ContractError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn save_logo(storage: &mut dyn Storage, logo: Logo) -> Result<LogoInfo, ContractError> {
+    verify_logo(&logo)?;
+    LOGO.save(storage, &logo)?;
+
+    Ok(match logo {
+        Logo::Url(url) => LogoInfo::Url(url),
+        Logo::Embedded(_) => LogoInfo::Embedded,
+    })
+}
+
+// mirrors the checks cw20-base applies before storing a logo
+fn verify_logo(logo: &Logo) -> Result<(), ContractError> {
+    let data = match logo {
+        Logo::Url(_) => return Ok(()),
+        Logo::Embedded(EmbeddedLogo::Svg(data)) => {
+            let preamble = data
+                .split_inclusive(|c| *c == b'>')
+                .next()
+                .unwrap_or_default();
+            if !(preamble.starts_with(b"<?xml ") && preamble.ends_with(b"?>")) {
+                return Err(cw20_base::ContractError::InvalidXmlPreamble {}.into());
+            }
+            data
+        }
+        Logo::Embedded(EmbeddedLogo::Png(data)) => {
+            if !data.starts_with(&PNG_HEADER) {
+                return Err(cw20_base::ContractError::InvalidPngHeader {}.into());
+            }
+            data
+        }
+    };
+
+    if data.len() > LOGO_SIZE_CAP {
+        return Err(cw20_base::ContractError::LogoTooBig {}.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::Binary;
+
+    #[test]
+    fn verify_logo_test() {
+        assert!(verify_logo(&Logo::Url("https://balanced.network/logo.png".to_string())).is_ok());
+
+        let png = Binary::from([&PNG_HEADER[..], &[0u8; 16][..]].concat());
+        assert!(verify_logo(&Logo::Embedded(EmbeddedLogo::Png(png))).is_ok());
+
+        let svg = Binary::from(b"<?xml version=\"1.0\"?><svg></svg>".as_slice());
+        assert!(verify_logo(&Logo::Embedded(EmbeddedLogo::Svg(svg))).is_ok());
+
+        let invalid = Binary::from(b"<svg></svg>".as_slice());
+        assert!(verify_logo(&Logo::Embedded(EmbeddedLogo::Svg(invalid))).is_err());
+
+        let too_big = Binary::from([&PNG_HEADER[..], &[0u8; LOGO_SIZE_CAP][..]].concat());
+        assert!(verify_logo(&Logo::Embedded(EmbeddedLogo::Png(too_big))).is_err());
+    }
+}
@@ -1,8 +1,12 @@
 #[cfg(feature = "injective")]
 use super::cw20_adapter::CW20Adapter;
 use cosmwasm_std::Addr;
-use cw_common::network_address::{NetId, NetworkAddress};
-use cw_storage_plus::Item;
+#[cfg(feature = "injective")]
+use cosmwasm_std::Uint128;
+use cw_common::network_address::{AddressFormat, NetId, NetworkAddress};
+use cw_storage_plus::{Item, Map};
+
+use crate::token_factory::TokenFactory;
 
 pub const OWNER: Item<Addr> = Item::new("owner");
 pub const X_CALL: Item<Addr> = Item::new("xCall");
@@ -11,6 +15,21 @@ pub const X_CALL_NETWORK_ADDRESS: Item<NetworkAddress> = Item::new("xCallBTPAddr
 pub const NID: Item<NetId> = Item::new("nid");
 pub const DESTINATION_TOKEN_ADDRESS: Item<Addr> = Item::new("hubAddress");
 pub const DESTINATION_TOKEN_NET: Item<NetId> = Item::new("hubNet");
+pub const X_CROSS_TRANSFER_METHOD: Item<String> = Item::new("xCrossTransferMethod");
+pub const X_CROSS_TRANSFER_REVERT_METHOD: Item<String> = Item::new("xCrossTransferRevertMethod");
+pub const CROSS_TRANSFER_NONCE: Item<u128> = Item::new("crossTransferNonce");
+pub const PAUSED: Item<bool> = Item::new("paused");
+pub const BLOCKLIST: Map<String, bool> = Map::new("blocklist");
+pub const RECIPIENT_FORMATS: Map<String, AddressFormat> = Map::new("recipient_formats");
+pub const TOKEN_FACTORY: Item<TokenFactory> = Item::new("token_factory");
+// tokenfactory rollback refunds of blocked owners, bank balances can't be frozen in place
+pub const FROZEN_REFUNDS: Map<String, u128> = Map::new("frozen_refunds");
 
 #[cfg(feature = "injective")]
 pub const CW20_ADAPTER: Item<CW20Adapter> = Item::new("cw20_adapter");
+// recipient and amount of the in-flight adapter receive, credited as cw20 if the adapter fails
+#[cfg(feature = "injective")]
+pub const PENDING_ADAPTER_RECEIVE: Item<(Addr, Uint128)> = Item::new("pending_adapter_receive");
+// sender and amount of the in-flight adapter redeem, credited as cw20 if the adapter fails
+#[cfg(feature = "injective")]
+pub const PENDING_ADAPTER_REDEEM: Item<(Addr, Uint128)> = Item::new("pending_adapter_redeem");
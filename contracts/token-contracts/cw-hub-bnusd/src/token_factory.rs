@@ -0,0 +1,133 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{coin, Addr, BankMsg, Coin, CosmosMsg, MessageInfo};
+
+pub const DEFAULT_PROTO_PACKAGE: &str = "osmosis.tokenfactory.v1beta1";
+
+// bank denom backend for chains running the tokenfactory module. Tokens are always minted to
+// and burned from this contract, which keeps to the message fields every fork agrees on.
+#[cw_serde]
+pub struct TokenFactory {
+    contract: Addr,
+    subdenom: String,
+    proto_package: String,
+}
+
+impl TokenFactory {
+    pub fn new(contract: Addr, subdenom: String, proto_package: Option<String>) -> Self {
+        Self {
+            contract,
+            subdenom,
+            proto_package: proto_package.unwrap_or_else(|| DEFAULT_PROTO_PACKAGE.to_string()),
+        }
+    }
+
+    pub fn denom(&self) -> String {
+        format!("factory/{}/{}", self.contract, self.subdenom)
+    }
+
+    pub fn create_denom(&self) -> CosmosMsg {
+        let mut value = vec![];
+        encode_string(1, self.contract.as_str(), &mut value);
+        encode_string(2, &self.subdenom, &mut value);
+        self.stargate("MsgCreateDenom", value)
+    }
+
+    // mint amount of the denom to this contract
+    pub fn mint(&self, amount: u128) -> CosmosMsg {
+        let mut value = vec![];
+        encode_string(1, self.contract.as_str(), &mut value);
+        encode_coin(2, &coin(amount, self.denom()), &mut value);
+        self.stargate("MsgMint", value)
+    }
+
+    // burn amount of the denom held by this contract
+    pub fn burn(&self, amount: u128) -> CosmosMsg {
+        let mut value = vec![];
+        encode_string(1, self.contract.as_str(), &mut value);
+        encode_coin(2, &coin(amount, self.denom()), &mut value);
+        self.stargate("MsgBurn", value)
+    }
+
+    pub fn transfer(&self, recipient: &Addr, amount: u128) -> CosmosMsg {
+        CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![coin(amount, self.denom())],
+        })
+    }
+
+    pub fn split_funds(&self, info: &MessageInfo) -> (u128, Vec<Coin>) {
+        let (denom, other): (Vec<Coin>, Vec<Coin>) = info
+            .funds
+            .clone()
+            .into_iter()
+            .partition(|f| f.denom == self.denom());
+        (denom.iter().map(|f| f.amount.u128()).sum(), other)
+    }
+
+    fn stargate(&self, msg: &str, value: Vec<u8>) -> CosmosMsg {
+        CosmosMsg::Stargate {
+            type_url: format!("/{}.{}", self.proto_package, msg),
+            value: value.into(),
+        }
+    }
+}
+
+fn encode_varint(mut value: u64, buf: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buf.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+// length delimited field (wire type 2)
+fn encode_bytes(field: u8, bytes: &[u8], buf: &mut Vec<u8>) {
+    buf.push((field << 3) | 2);
+    encode_varint(bytes.len() as u64, buf);
+    buf.extend_from_slice(bytes);
+}
+
+fn encode_string(field: u8, value: &str, buf: &mut Vec<u8>) {
+    encode_bytes(field, value.as_bytes(), buf);
+}
+
+fn encode_coin(field: u8, coin: &Coin, buf: &mut Vec<u8>) {
+    let mut value = vec![];
+    encode_string(1, &coin.denom, &mut value);
+    encode_string(2, &coin.amount.to_string(), &mut value);
+    encode_bytes(field, &value, buf);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_mint_test() {
+        let token_factory = TokenFactory::new(Addr::unchecked("hub"), "bnusd".to_string(), None);
+        assert_eq!(token_factory.denom(), "factory/hub/bnusd");
+
+        let expected: [&[u8]; 6] = [
+            &[0x0a, 3],
+            b"hub",
+            &[0x12, 23, 0x0a, 17],
+            b"factory/hub/bnusd",
+            &[0x12, 2],
+            b"10",
+        ];
+        match token_factory.mint(10) {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, "/osmosis.tokenfactory.v1beta1.MsgMint");
+                assert_eq!(value.to_vec(), expected.concat());
+            }
+            _ => panic!("Unexpected message"),
+        }
+    }
+
+    #[test]
+    fn encode_varint_test() {
+        let mut buf = vec![];
+        encode_varint(300, &mut buf);
+        assert_eq!(buf, vec![0xac, 0x02]);
+    }
+}
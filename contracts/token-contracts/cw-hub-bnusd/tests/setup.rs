@@ -211,6 +211,13 @@ pub fn init_token(mut ctx: TestContext, _x_call_address: String) -> TestContext
                 x_call: Addr::unchecked(_x_call_address).into_string(),
                 hub_address: "icon/cx9876543210fedcba9876543210fedcba98765432".to_owned(),
                 manager: Addr::unchecked("manager"),
+                marketing: None,
+                name: None,
+                symbol: None,
+                decimals: None,
+                cross_transfer_method: None,
+                cross_transfer_revert_method: None,
+                token_factory: None,
             },
             &[],
             "HubToken",
@@ -237,6 +244,7 @@ pub fn init_xcall_manager(mut ctx: TestContext) -> TestContext {
                     sources: vec![],
                     destinations: vec![],
                 },
+                timelock: None,
             },
             &[],
             "IbcCore",